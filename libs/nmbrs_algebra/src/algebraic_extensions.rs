@@ -1,4 +1,4 @@
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use core::ops::{Add, Div, Mul, Neg, Sub};
 
 pub trait AddIdentity: Sized {
     fn zero() -> Self;
@@ -16,10 +16,16 @@ pub trait NumericSemiGroup: Sized + AddIdentity + Add<Output = Self> + PartialEq
 }
 
 impl NumericSemiGroup for usize {}
+impl NumericSemiGroup for u8 {}
+impl NumericSemiGroup for u16 {}
+impl NumericSemiGroup for u32 {}
+impl NumericSemiGroup for u64 {}
+impl NumericSemiGroup for u128 {}
 impl NumericSemiGroup for i8 {}
 impl NumericSemiGroup for i16 {}
 impl NumericSemiGroup for i32 {}
 impl NumericSemiGroup for i64 {}
+impl NumericSemiGroup for i128 {}
 impl NumericSemiGroup for f32 {}
 impl NumericSemiGroup for f64 {}
 
@@ -77,6 +83,14 @@ impl<T> NumericRing for T where T: NumericGroup + MulIdentity + Mul<Output = Sel
 
 /// Mimic features of a [(mathematical) field](https://en.wikipedia.org/wiki/Field_(mathematics)).
 pub trait NumericField: NumericRing + Div<Output = Self> {
+    /// The multiplicative inverse $1 / a$.
+    ///
+    /// NOTE: The integers are no field, their inverse truncates to zero for $|a| > 1$.
+    /// Hence divide by `a` rather than multiplying with its inverse, see `checked_div`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` is zero, see `checked_inverse` for a non-panicking alternative.
     fn inverse(a: Self) -> Self
     where
         Self: Copy,
@@ -86,6 +100,21 @@ pub trait NumericField: NumericRing + Div<Output = Self> {
         }
         Self::one() / a
     }
+
+    /// The multiplicative inverse $1 / a$, or `None` if `a` is zero.
+    ///
+    /// NOTE: As for `inverse`, the inverse of an integer truncates to zero for $|a| > 1$.
+    fn checked_inverse(a: Self) -> Option<Self> {
+        Self::checked_div(Self::one(), a)
+    }
+
+    /// The quotient $a / b$, or `None` if `b` is zero.
+    fn checked_div(a: Self, b: Self) -> Option<Self> {
+        if b == Self::zero() {
+            return None;
+        }
+        Some(a / b)
+    }
 }
 
 impl<T> NumericField for T where T: NumericRing + Div<Output = Self> {}
@@ -114,31 +143,439 @@ macro_rules! impl_mul_identity {
 
 // implement MulIdentity
 impl_mul_identity! { usize }
+impl_mul_identity! { u8 }
+impl_mul_identity! { u16 }
+impl_mul_identity! { u32 }
+impl_mul_identity! { u64 }
+impl_mul_identity! { u128 }
 impl_mul_identity! { i8 }
 impl_mul_identity! { i16 }
 impl_mul_identity! { i32 }
 impl_mul_identity! { i64 }
+impl_mul_identity! { i128 }
 impl_mul_identity! { f32 }
 impl_mul_identity! { f64 }
 
 // implement AddIdentity
-impl_add_identity! { usize}
+impl_add_identity! { usize }
+impl_add_identity! { u8 }
+impl_add_identity! { u16 }
+impl_add_identity! { u32 }
+impl_add_identity! { u64 }
+impl_add_identity! { u128 }
 impl_add_identity! { i8 }
 impl_add_identity! { i16 }
 impl_add_identity! { i32 }
 impl_add_identity! { i64 }
+impl_add_identity! { i128 }
 impl_add_identity! { f32 }
 impl_add_identity! { f64 }
 
+/// The rationals of the `num` crates, e.g. `Rational64` and `BigRational`, and the `Numeric` wrapper for
+/// any other type implementing `num_traits::Zero` and `One`, provided by the `num-traits` feature.
+#[cfg(feature = "num-traits")]
+pub use num_impls::Numeric;
+
+#[cfg(feature = "num-traits")]
+mod num_impls {
+    use super::{AddIdentity, MulIdentity, NumericSemiGroup};
+    use core::ops::{Add, Div, Mul, Neg, Sub};
+    use num_rational::Ratio;
+    use num_traits::{One, Zero};
+
+    /// Wraps a numeric type of the `num` crates, e.g. `BigInt`, to implement the algebraic traits
+    /// by means of `num_traits::Zero` and `One`.
+    ///
+    /// NOTE: A blanket impl over all types implementing `Zero` and `One` would conflict with the concrete impls
+    /// for the primitive types, hence types other than the rationals participate through this wrapper only.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Numeric<T>(pub T);
+
+    macro_rules! impl_numeric_op {
+        ($op:ident, $method:ident) => {
+            impl<T: $op<Output = T>> $op for Numeric<T> {
+                type Output = Self;
+                fn $method(self, rhs: Self) -> Self {
+                    Numeric(self.0.$method(rhs.0))
+                }
+            }
+        };
+    }
+
+    impl_numeric_op! { Add, add }
+    impl_numeric_op! { Sub, sub }
+    impl_numeric_op! { Mul, mul }
+    impl_numeric_op! { Div, div }
+
+    impl<T: Neg<Output = T>> Neg for Numeric<T> {
+        type Output = Self;
+        fn neg(self) -> Self {
+            Numeric(-self.0)
+        }
+    }
+
+    impl<T: Zero> AddIdentity for Numeric<T> {
+        fn zero() -> Self {
+            Numeric(T::zero())
+        }
+    }
+
+    impl<T: One> MulIdentity for Numeric<T> {
+        fn one() -> Self {
+            Numeric(T::one())
+        }
+    }
+
+    impl<T: Zero + PartialEq> NumericSemiGroup for Numeric<T> {}
+
+    impl<T> AddIdentity for Ratio<T>
+    where
+        Ratio<T>: Zero,
+    {
+        fn zero() -> Self {
+            <Self as Zero>::zero()
+        }
+    }
+
+    impl<T> MulIdentity for Ratio<T>
+    where
+        Ratio<T>: One,
+    {
+        fn one() -> Self {
+            <Self as One>::one()
+        }
+    }
+
+    impl<T> NumericSemiGroup for Ratio<T> where Ratio<T>: Zero + PartialEq {}
+}
+
 pub trait MidPoint: Sized {
     fn mid_point(&self, b: Self) -> Self;
 }
 
+/// The mid point $(a + b) / 2$, evaluated as $a / 2 + b / 2$ plus the halved remainders such that
+/// bounded integers do not overflow. For integers the mid point is truncated towards zero.
 impl<T> MidPoint for T
 where
-    T: NumericField + Copy,
+    T: NumericSemiGroup + MulIdentity + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Copy,
 {
     fn mid_point(&self, b: Self) -> Self {
-        (*self + b) / (T::one() + T::one())
+        let two = T::one() + T::one();
+        let (half_a, half_b) = (*self / two, b / two);
+        let remainders = (*self - half_a * two) + (b - half_b * two);
+        half_a + half_b + remainders / two
+    }
+}
+
+/// The (principal) square root, defined for the floating point types.
+pub trait Sqrt: Sized {
+    fn sqrt(self) -> Self;
+}
+
+/// Raise to an integer power.
+pub trait Powi: Sized {
+    fn powi(self, n: i32) -> Self;
+}
+
+/// Raise to a (real) power.
+pub trait Powf: Sized {
+    fn powf(self, n: Self) -> Self;
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_float_powers {
+    ($impl_type:ty) => {
+        impl Sqrt for $impl_type {
+            fn sqrt(self) -> Self {
+                <$impl_type>::sqrt(self)
+            }
+        }
+
+        impl Powi for $impl_type {
+            fn powi(self, n: i32) -> Self {
+                <$impl_type>::powi(self, n)
+            }
+        }
+
+        impl Powf for $impl_type {
+            fn powf(self, n: Self) -> Self {
+                <$impl_type>::powf(self, n)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_float_powers! { f32 }
+#[cfg(feature = "std")]
+impl_float_powers! { f64 }
+
+/// The [natural logarithm](https://en.wikipedia.org/wiki/Natural_logarithm).
+pub trait Ln: Sized {
+    fn ln(self) -> Self;
+}
+
+/// The [exponential function](https://en.wikipedia.org/wiki/Exponential_function).
+pub trait Exp: Sized {
+    fn exp(self) -> Self;
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_float_exp_ln {
+    ($impl_type:ty) => {
+        impl Ln for $impl_type {
+            fn ln(self) -> Self {
+                <$impl_type>::ln(self)
+            }
+        }
+
+        impl Exp for $impl_type {
+            fn exp(self) -> Self {
+                <$impl_type>::exp(self)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_float_exp_ln! { f32 }
+#[cfg(feature = "std")]
+impl_float_exp_ln! { f64 }
+
+/// The [trigonometric functions](https://en.wikipedia.org/wiki/Trigonometric_functions) required for angles,
+/// with arguments in radians.
+pub trait Trigonometry: Sized {
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    /// The inverse of the cosine, in the range $[0, \pi]$.
+    fn acos(self) -> Self;
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_float_trigonometry {
+    ($impl_type:ty) => {
+        impl Trigonometry for $impl_type {
+            fn sin(self) -> Self {
+                <$impl_type>::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                <$impl_type>::cos(self)
+            }
+
+            fn acos(self) -> Self {
+                <$impl_type>::acos(self)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_float_trigonometry! { f32 }
+#[cfg(feature = "std")]
+impl_float_trigonometry! { f64 }
+
+/// Mimic features of the [real numbers](https://en.wikipedia.org/wiki/Real_number), that is an ordered field
+/// which additionally provides roots and powers.
+pub trait RealField: NumericField + PartialOrd + Abs + Sqrt + Powi + Powf {}
+
+impl<T> RealField for T where T: NumericField + PartialOrd + Abs + Sqrt + Powi + Powf {}
+
+/// The [absolute value](https://en.wikipedia.org/wiki/Absolute_value), defined for the signed numeric types.
+pub trait Abs: Sized {
+    fn abs(self) -> Self;
+}
+
+/// The [sign function](https://en.wikipedia.org/wiki/Sign_function), returning `-1`, `0` or `1`.
+pub trait Signum: Sized {
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_abs {
+    ($impl_type:ty) => {
+        impl Abs for $impl_type {
+            fn abs(self) -> Self {
+                <$impl_type>::abs(self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_int_signum {
+    ($impl_type:ty) => {
+        impl Signum for $impl_type {
+            fn signum(self) -> Self {
+                <$impl_type>::signum(self)
+            }
+        }
+    };
+}
+
+/// In contrast to the std implementation, the sign of (positive or negative) zero is zero.
+macro_rules! impl_float_signum {
+    ($impl_type:ty) => {
+        impl Signum for $impl_type {
+            fn signum(self) -> Self {
+                if self == 0.0 {
+                    return 0.0;
+                }
+                <$impl_type>::signum(self)
+            }
+        }
+    };
+}
+
+impl_abs! { i8 }
+impl_abs! { i16 }
+impl_abs! { i32 }
+impl_abs! { i64 }
+impl_abs! { f32 }
+impl_abs! { f64 }
+
+impl_int_signum! { i8 }
+impl_int_signum! { i16 }
+impl_int_signum! { i32 }
+impl_int_signum! { i64 }
+impl_float_signum! { f32 }
+impl_float_signum! { f64 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Abs, MidPoint, NumericField, Signum};
+    #[cfg(feature = "num-traits")]
+    use super::{AddIdentity, MulIdentity, Numeric};
+    #[cfg(feature = "std")]
+    use super::{Exp, Ln, Powf, Powi, Sqrt, Trigonometry};
+
+    #[test]
+    fn mid_point() {
+        assert_eq!(1.0_f64.mid_point(2.0), 1.5);
+        assert_eq!((-1.0_f32).mid_point(2.0), 0.5);
+
+        assert_eq!(1_i32.mid_point(3), 2);
+        assert_eq!(1_i32.mid_point(4), 2);
+        assert_eq!((-4_i32).mid_point(-1), -2);
+        assert_eq!(3_usize.mid_point(8), 5);
+        assert_eq!(3_u8.mid_point(8), 5);
+        assert_eq!(7_u128.mid_point(9), 8);
+        assert_eq!((-7_i128).mid_point(-9), -8);
+
+        // no overflow
+        assert_eq!(100_i8.mid_point(120), 110);
+        assert_eq!(i8::MIN.mid_point(i8::MAX), -1);
+        assert_eq!(i64::MAX.mid_point(i64::MAX), i64::MAX);
+        assert_eq!(u64::MAX.mid_point(u64::MAX - 2), u64::MAX - 1);
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!(Abs::abs(-3.0_f64), 3.0);
+        assert_eq!(Abs::abs(3.0_f32), 3.0);
+        assert_eq!(Abs::abs(-3_i8), 3);
+        assert_eq!(Abs::abs(0_i32), 0);
+        assert_eq!(Abs::abs(-7_i64), 7);
+    }
+
+    #[test]
+    fn signum() {
+        assert_eq!(Signum::signum(-3.0_f64), -1.0);
+        assert_eq!(Signum::signum(0.0_f64), 0.0);
+        assert_eq!(Signum::signum(-0.0_f32), 0.0);
+        assert_eq!(Signum::signum(2.5_f32), 1.0);
+        assert!(Signum::signum(f64::NAN).is_nan());
+
+        assert_eq!(Signum::signum(-3_i16), -1);
+        assert_eq!(Signum::signum(0_i32), 0);
+        assert_eq!(Signum::signum(42_i64), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn powers() {
+        assert_eq!(Sqrt::sqrt(4.0_f64), 2.0);
+        assert_eq!(Sqrt::sqrt(2.25_f32), 1.5);
+        assert_eq!(Powi::powi(2.0_f64, 3), 8.0);
+        assert_eq!(Powi::powi(2.0_f32, -1), 0.5);
+        assert_eq!(Powf::powf(9.0_f64, 0.5), 3.0);
+        assert_eq!(Powf::powf(8.0_f32, 1.0 / 3.0), 2.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn exp_ln() {
+        assert_eq!(Exp::exp(0.0_f64), 1.0);
+        assert_eq!(Ln::ln(1.0_f32), 0.0);
+        assert!((Ln::ln(Exp::exp(2.5_f64)) - 2.5).abs() < 1e-15);
+        assert!(Ln::ln(-1.0_f64).is_nan());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn trigonometry() {
+        assert_eq!(Trigonometry::sin(0.0_f64), 0.0);
+        assert_eq!(Trigonometry::cos(0.0_f32), 1.0);
+        assert_eq!(Trigonometry::acos(1.0_f64), 0.0);
+        assert!((Trigonometry::acos(-1.0_f64) - std::f64::consts::PI).abs() < 1e-15);
+    }
+
+    #[cfg(feature = "std")]
+    fn norm<T: super::RealField + Copy>(x: T, y: T) -> T {
+        (x.powi(2) + y.powi(2)).sqrt()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn real_field() {
+        assert_eq!(norm(3.0_f64, -4.0), 5.0);
+        assert_eq!(norm(3.0_f32, -4.0), 5.0);
+    }
+
+    #[test]
+    fn checked_div() {
+        assert_eq!(NumericField::checked_div(1.0_f64, 4.0), Some(0.25));
+        assert_eq!(NumericField::checked_div(1.0_f64, 0.0), None);
+        assert_eq!(NumericField::checked_div(1.0_f32, -0.0), None);
+
+        assert_eq!(NumericField::checked_div(10_i64, 5), Some(2));
+        assert_eq!(NumericField::checked_div(7_i32, 2), Some(3));
+        assert_eq!(NumericField::checked_div(1_i16, 0), None);
+    }
+
+    #[test]
+    fn checked_inverse() {
+        assert_eq!(NumericField::checked_inverse(4.0_f64), Some(0.25));
+        assert_eq!(NumericField::checked_inverse(0.0_f64), None);
+        assert_eq!(NumericField::checked_inverse(-0.0_f32), None);
+
+        // integer division truncates
+        assert_eq!(NumericField::checked_inverse(1_i32), Some(1));
+        assert_eq!(NumericField::checked_inverse(2_i64), Some(0));
+        assert_eq!(NumericField::checked_inverse(0_i16), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide by zero")]
+    fn inverse_of_zero() {
+        NumericField::inverse(0.0_f64);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn numeric_wrapper() {
+        use num_rational::BigRational;
+
+        let big = |n: i64| Numeric(BigRational::from_integer(n.into()).to_integer());
+
+        let sum = (1..=100).map(big).fold(Numeric::zero(), |acc, n| acc + n);
+        assert_eq!(sum, big(5050));
+
+        let product = (1..=25).map(big).fold(Numeric::one(), |acc, n| acc * n);
+        // 25! exceeds the range of `i64`
+        assert!(product > big(i64::MAX));
+        assert_eq!(
+            product / big(25) / big(24),
+            (1..=23).map(big).fold(Numeric::one(), |acc, n| acc * n)
+        );
+        assert_eq!(-big(3) - big(4), big(-7));
     }
 }
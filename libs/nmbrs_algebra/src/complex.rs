@@ -0,0 +1,222 @@
+use crate::algebraic_extensions::{
+    AddIdentity, MulIdentity, NumericField, NumericGroup, NumericRing, NumericSemiGroup, RealField,
+};
+use core::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// A [complex number](https://en.wikipedia.org/wiki/Complex_number) $z = a + bi$ over the (real) field `F`.
+///
+/// ```rust
+/// use nmbrs_algebra::Complex;
+///
+/// let z = Complex::new(1.0, 2.0);
+/// assert_eq!(z * z.conj(), Complex::new(5.0, 0.0));
+/// assert_eq!(Complex::<f64>::i() * Complex::i(), Complex::new(-1.0, 0.0));
+/// assert_eq!(Complex::new(3.0, -4.0).norm_sqr(), 25.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<F> {
+    pub re: F,
+    pub im: F,
+}
+
+impl<F> Complex<F> {
+    pub fn new(re: F, im: F) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<F: AddIdentity> Complex<F> {
+    /// The real number `re` embedded into the complex numbers.
+    pub fn from_real(re: F) -> Self {
+        Self { re, im: F::zero() }
+    }
+}
+
+impl<F: AddIdentity + MulIdentity> Complex<F> {
+    /// The imaginary unit $i$ with $i^2 = -1$.
+    pub fn i() -> Self {
+        Self {
+            re: F::zero(),
+            im: F::one(),
+        }
+    }
+}
+
+impl<F: NumericRing + Copy> Complex<F> {
+    /// The [complex conjugate](https://en.wikipedia.org/wiki/Complex_conjugate) $a - bi$.
+    pub fn conj(&self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    /// The squared absolute value $a^2 + b^2$, which avoids taking a root.
+    pub fn norm_sqr(&self) -> F {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Multiplies both parts by the real `factor`.
+    pub fn scale(&self, factor: F) -> Self {
+        Self {
+            re: self.re * factor,
+            im: self.im * factor,
+        }
+    }
+}
+
+impl<F: RealField + Copy> Complex<F> {
+    /// The absolute value, or modulus, $|z| = \sqrt{a^2 + b^2}$.
+    pub fn norm(&self) -> F {
+        self.norm_sqr().sqrt()
+    }
+}
+
+impl<F: AddIdentity> From<F> for Complex<F> {
+    fn from(re: F) -> Self {
+        Self::from_real(re)
+    }
+}
+
+impl<F> Display for Complex<F>
+where
+    F: Display + NumericGroup + PartialOrd + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.im < F::zero() {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+impl<F: AddIdentity> AddIdentity for Complex<F> {
+    fn zero() -> Self {
+        Self {
+            re: F::zero(),
+            im: F::zero(),
+        }
+    }
+}
+
+impl<F: AddIdentity + MulIdentity> MulIdentity for Complex<F> {
+    fn one() -> Self {
+        Self {
+            re: F::one(),
+            im: F::zero(),
+        }
+    }
+}
+
+impl<F: NumericSemiGroup> NumericSemiGroup for Complex<F> {}
+
+impl<F: Add<Output = F>> Add for Complex<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+}
+
+impl<F: Sub<Output = F>> Sub for Complex<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            re: self.re - rhs.re,
+            im: self.im - rhs.im,
+        }
+    }
+}
+
+impl<F: Neg<Output = F>> Neg for Complex<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl<F: NumericRing + Copy> Mul for Complex<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+/// NOTE: As for the underlying field, dividing by zero is not guarded against.
+impl<F: NumericField + Copy> Div for Complex<F> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let denominator = rhs.norm_sqr();
+        let numerator = self * rhs.conj();
+        Self {
+            re: numerator.re / denominator,
+            im: numerator.im / denominator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Complex;
+    use crate::algebraic_extensions::{AddIdentity, MulIdentity, NumericField};
+
+    #[test]
+    fn arithmetic() {
+        let z = Complex::new(1.0, 2.0);
+        let w = Complex::new(-3.0, 0.5);
+
+        assert_eq!(z + w, Complex::new(-2.0, 2.5));
+        assert_eq!(z - w, Complex::new(4.0, 1.5));
+        assert_eq!(-z, Complex::new(-1.0, -2.0));
+        assert_eq!(z * w, Complex::new(-4.0, -5.5));
+        assert_eq!((z * w) / w, z);
+        assert_eq!(z.scale(2.0), Complex::new(2.0, 4.0));
+        assert_eq!(Complex::from(2.0) * z, z.scale(2.0));
+    }
+
+    #[test]
+    fn field() {
+        let z = Complex::new(3_i32, -4);
+        assert_eq!(z.conj(), Complex::new(3, 4));
+        assert_eq!(z.norm_sqr(), 25);
+        assert_eq!(z * Complex::one(), z);
+        assert_eq!(z + Complex::zero(), z);
+
+        let z = Complex::new(0.0, 2.0);
+        assert_eq!(
+            NumericField::checked_div(Complex::one(), z),
+            Some(Complex::new(0.0, -0.5))
+        );
+        assert_eq!(NumericField::checked_div(z, Complex::zero()), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn norm() {
+        assert_eq!(Complex::new(-5.0, 12.0).norm(), 13.0);
+        assert_eq!(Complex::<f32>::i().norm(), 1.0);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Complex::new(1.5, -2.0).to_string(), "1.5-2i");
+        assert_eq!(Complex::new(0, 1).to_string(), "0+1i");
+    }
+}
@@ -0,0 +1,170 @@
+use crate::algebraic_extensions::{AddIdentity, NumericGroup, NumericRing};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+/// A vector whose dimension is only known at runtime, see `Vector` for the statically sized counterpart.
+///
+/// NOTE: The binary operations panic if the dimensions of the operands differ.
+///
+/// ```rust
+/// use nmbrs_algebra::DVector;
+///
+/// let v = DVector::from(vec![1.0, 2.0, 3.0]);
+/// let w = DVector::from(vec![0.5, 0.0, -1.0]);
+/// assert_eq!(v.dot(&w), -2.5);
+/// assert_eq!(&v + &w, DVector::from(vec![1.5, 2.0, 2.0]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DVector<F> {
+    v: Vec<F>,
+}
+
+impl<F> DVector<F> {
+    pub fn new(v: Vec<F>) -> Self {
+        Self { v }
+    }
+
+    /// The dimension of the vector.
+    pub fn len(&self) -> usize {
+        self.v.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.v.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[F] {
+        &self.v
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, F> {
+        self.v.iter()
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(&F, &F) -> F) -> Self {
+        assert_eq!(self.len(), other.len(), "dimensions of the operands differ");
+        self.v
+            .iter()
+            .zip(other.v.iter())
+            .map(|(x, y)| f(x, y))
+            .collect()
+    }
+}
+
+impl<F: AddIdentity + Clone> DVector<F> {
+    /// The zero vector of dimension `n`.
+    pub fn zeros(n: usize) -> Self {
+        Self {
+            v: vec![F::zero(); n],
+        }
+    }
+}
+
+impl<F: NumericRing + Copy> DVector<F> {
+    /// The [dot product](https://en.wikipedia.org/wiki/Dot_product) $\sum_i x_i y_i$.
+    pub fn dot(&self, other: &Self) -> F {
+        assert_eq!(self.len(), other.len(), "dimensions of the operands differ");
+        self.v
+            .iter()
+            .zip(other.v.iter())
+            .fold(F::zero(), |acc, (x, y)| acc + *x * *y)
+    }
+}
+
+impl<F> From<Vec<F>> for DVector<F> {
+    fn from(v: Vec<F>) -> Self {
+        Self { v }
+    }
+}
+
+impl<F> From<DVector<F>> for Vec<F> {
+    fn from(v: DVector<F>) -> Self {
+        v.v
+    }
+}
+
+impl<F> FromIterator<F> for DVector<F> {
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        Self {
+            v: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<F> Index<usize> for DVector<F> {
+    type Output = F;
+
+    fn index(&self, idx: usize) -> &F {
+        &self.v[idx]
+    }
+}
+
+impl<F> IndexMut<usize> for DVector<F> {
+    fn index_mut(&mut self, idx: usize) -> &mut F {
+        &mut self.v[idx]
+    }
+}
+
+impl<F: NumericGroup + Copy> Add for &DVector<F> {
+    type Output = DVector<F>;
+
+    fn add(self, rhs: Self) -> DVector<F> {
+        self.zip_with(rhs, |x, y| *x + *y)
+    }
+}
+
+impl<F: NumericGroup + Copy> Sub for &DVector<F> {
+    type Output = DVector<F>;
+
+    fn sub(self, rhs: Self) -> DVector<F> {
+        self.zip_with(rhs, |x, y| *x - *y)
+    }
+}
+
+impl<F: NumericGroup + Copy> Neg for &DVector<F> {
+    type Output = DVector<F>;
+
+    fn neg(self) -> DVector<F> {
+        self.v.iter().map(|x| -*x).collect()
+    }
+}
+
+impl<F: NumericRing + Copy> Mul<F> for &DVector<F> {
+    type Output = DVector<F>;
+
+    fn mul(self, rhs: F) -> DVector<F> {
+        self.v.iter().map(|x| *x * rhs).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DVector;
+
+    #[test]
+    fn arithmetic() {
+        let v = DVector::new(vec![1, 2, 3]);
+        let w = DVector::from(vec![4, -5, 6]);
+
+        assert_eq!(&v + &w, DVector::new(vec![5, -3, 9]));
+        assert_eq!(&v - &w, DVector::new(vec![-3, 7, -3]));
+        assert_eq!(-&v, DVector::new(vec![-1, -2, -3]));
+        assert_eq!(&v * 2, DVector::new(vec![2, 4, 6]));
+        assert_eq!(v.dot(&w), 12);
+        assert_eq!(v[1], 2);
+        assert_eq!(v.len(), 3);
+    }
+
+    #[test]
+    fn zeros() {
+        let v: DVector<f64> = DVector::zeros(4);
+        assert_eq!(v.as_slice(), &[0.0; 4]);
+        assert!(DVector::<f64>::zeros(0).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn dimension_mismatch() {
+        let _ = &DVector::new(vec![1.0]) + &DVector::new(vec![1.0, 2.0]);
+    }
+}
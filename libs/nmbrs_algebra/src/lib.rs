@@ -1,7 +1,24 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 mod algebraic_extensions;
+mod complex;
+mod dvector;
+mod linalg;
+mod matrix;
 mod vector_space;
 
+#[cfg(feature = "num-traits")]
+pub use algebraic_extensions::Numeric;
 pub use algebraic_extensions::{
-    MidPoint, NumericField, NumericGroup, NumericRing, NumericSemiGroup,
+    Abs, AddIdentity, Exp, Ln, MidPoint, MulIdentity, NumericField, NumericGroup, NumericRing,
+    NumericSemiGroup, Powf, Powi, RealField, Signum, Sqrt, Trigonometry,
+};
+pub use complex::Complex;
+pub use dvector::DVector;
+pub use linalg::{conjugate_gradient, solve_linear_system, solve_linear_system_dyn};
+pub use matrix::Matrix;
+pub use vector_space::{
+    DimensionMismatch, Norm, Vector, VectorSpace, VectorSpaceF32, VectorSpaceF64,
 };
-pub use vector_space::{Vector, VectorSpace};
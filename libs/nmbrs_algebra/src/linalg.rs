@@ -0,0 +1,269 @@
+use crate::DVector;
+use alloc::{vec, vec::Vec};
+
+/// Solves the linear system $Ax = b$ by [Gaussian elimination](https://en.wikipedia.org/wiki/Gaussian_elimination)
+/// with partial pivoting.
+/// Returns `None` if the matrix `a` is (numerically) singular.
+///
+/// ```rust
+/// use nmbrs_algebra::solve_linear_system;
+///
+/// let a = [[2.0, 1.0], [1.0, 3.0]];
+/// let b = [3.0, 5.0];
+/// let x = solve_linear_system(a, b).unwrap();
+/// assert!((x[0] - 0.8).abs() < 1e-12 && (x[1] - 1.4).abs() < 1e-12);
+/// ```
+pub fn solve_linear_system<const N: usize>(
+    mut a: [[f64; N]; N],
+    mut b: [f64; N],
+) -> Option<[f64; N]> {
+    let mut x = [0.0; N];
+    gaussian_elimination(&mut a, &mut b, &mut x)?;
+    Some(x)
+}
+
+/// Solves the linear system $Ax = b$ for a matrix whose dimension is only known at runtime, given by its rows.
+/// See `solve_linear_system`.
+/// Returns `None` if the matrix `a` is (numerically) singular or not of dimension `b.len()` x `b.len()`.
+pub fn solve_linear_system_dyn(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    if a.len() != n || a.iter().any(|row| row.len() != n) {
+        return None;
+    }
+
+    let mut x = vec![0.0; n];
+    gaussian_elimination(&mut a, &mut b, &mut x)?;
+    Some(x)
+}
+
+/// Gaussian elimination with partial pivoting, storing the solution in `x`.
+/// Assumes that all dimensions agree.
+fn gaussian_elimination<R: AsMut<[f64]>>(a: &mut [R], b: &mut [f64], x: &mut [f64]) -> Option<()> {
+    let n = b.len();
+
+    // scale each equation to a largest coefficient of one, such that the pivots are compared to their own rows
+    // rather than to the largest entry of a badly scaled matrix
+    for (row, b_row) in a.iter_mut().zip(b.iter_mut()) {
+        let row = row.as_mut();
+        let scale = row.iter().fold(0.0, |acc: f64, x| acc.max(x.abs()));
+        if !scale.is_finite() || scale == 0.0 {
+            return None;
+        }
+        row.iter_mut().for_each(|x| *x /= scale);
+        *b_row /= scale;
+    }
+
+    forward_elimination(a, b, n as f64 * f64::EPSILON)?;
+
+    // back substitution
+    for row in (0..n).rev() {
+        let a_row = a[row].as_mut();
+        let sum: f64 = ((row + 1)..n).map(|k| a_row[k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a_row[row];
+    }
+
+    Some(())
+}
+
+/// The [determinant](https://en.wikipedia.org/wiki/Determinant) of the square matrix `a`, given by its rows,
+/// as the signed product of the pivots of the Gaussian elimination.
+/// No pivot is treated as zero unless it vanishes exactly, such that small but non-zero determinants are retained.
+pub(crate) fn determinant<R: AsMut<[f64]>>(a: &mut [R]) -> f64 {
+    let mut b = vec![0.0; a.len()];
+    match forward_elimination(a, &mut b, 0.0) {
+        Some(n_swaps) => {
+            let sign = if n_swaps.is_multiple_of(2) { 1.0 } else { -1.0 };
+            (0..a.len()).fold(sign, |det, idx| det * a[idx].as_mut()[idx])
+        }
+        None => 0.0,
+    }
+}
+
+/// Reduces `a` to an upper triangular matrix by row operations with partial pivoting, applied to `b` alike.
+/// Returns the number of row swaps, or `None` if a pivot does not exceed `threshold` in magnitude.
+fn forward_elimination<R: AsMut<[f64]>>(
+    a: &mut [R],
+    b: &mut [f64],
+    threshold: f64,
+) -> Option<usize> {
+    let n = b.len();
+
+    let mut n_swaps = 0;
+    for col in 0..n {
+        let (pivot_row, pivot_abs) = (col..n)
+            .map(|row| (row, a[row].as_mut()[col].abs()))
+            .max_by(|(_, x), (_, y)| x.total_cmp(y))?;
+        if pivot_abs <= threshold {
+            return None;
+        }
+        if pivot_row != col {
+            a.swap(col, pivot_row);
+            b.swap(col, pivot_row);
+            n_swaps += 1;
+        }
+
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot = upper[col].as_mut();
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let row = row.as_mut();
+            let factor = row[col] / pivot[col];
+            for (a_rk, p_k) in row.iter_mut().zip(pivot.iter()).skip(col) {
+                *a_rk -= factor * p_k;
+            }
+            b[col + 1 + offset] -= factor * b[col];
+        }
+    }
+
+    Some(n_swaps)
+}
+
+/// Solves the linear system $Ax = b$ for a [symmetric positive-definite](https://en.wikipedia.org/wiki/Definite_matrix) matrix $A$
+/// by the [conjugate gradient method](https://en.wikipedia.org/wiki/Conjugate_gradient_method),
+/// accessing $A$ only through the matrix-vector product `apply_a`, starting from $x_0 = 0$.
+/// Iterates until the residual satisfies $\|b - Ax\| \leq$ `tol`.
+/// Returns `None` if this does not happen within `max_iter` iterations or if $A$ turns out not to be positive-definite.
+///
+/// ```rust
+/// use nmbrs_algebra::{conjugate_gradient, DVector};
+///
+/// // the tridiagonal matrix with 2 on the diagonal and -1 off the diagonal
+/// let apply_a = |x: &DVector<f64>| {
+///     let n = x.len();
+///     (0..n)
+///         .map(|i| {
+///             let left = if i > 0 { x[i - 1] } else { 0.0 };
+///             let right = if i + 1 < n { x[i + 1] } else { 0.0 };
+///             2.0 * x[i] - left - right
+///         })
+///         .collect()
+/// };
+/// let b = DVector::from(vec![1.0; 50]);
+/// let x = conjugate_gradient(apply_a, &b, 1e-10, 100).unwrap();
+/// assert!((x[0] - 25.0).abs() < 1e-8);
+/// ```
+pub fn conjugate_gradient(
+    apply_a: impl Fn(&DVector<f64>) -> DVector<f64>,
+    b: &DVector<f64>,
+    tol: f64,
+    max_iter: usize,
+) -> Option<DVector<f64>> {
+    let mut x = DVector::zeros(b.len());
+    let mut r = b.clone();
+    let mut p = r.clone();
+    let mut r_squared = r.dot(&r);
+    let tol_squared = tol * tol;
+
+    for _ in 0..=max_iter {
+        if r_squared <= tol_squared {
+            return Some(x);
+        }
+        let a_p = apply_a(&p);
+        let p_a_p = p.dot(&a_p);
+        if p_a_p.is_nan() || p_a_p <= 0.0 {
+            return None;
+        }
+        let alpha = r_squared / p_a_p;
+        x = &x + &(&p * alpha);
+        r = &r - &(&a_p * alpha);
+
+        let r_squared_next = r.dot(&r);
+        p = &r + &(&p * (r_squared_next / r_squared));
+        r_squared = r_squared_next;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{conjugate_gradient, solve_linear_system, solve_linear_system_dyn};
+    use crate::DVector;
+
+    #[test]
+    fn solve_3x3() {
+        // requires pivoting, as the first entry vanishes
+        let a = [[0.0, 2.0, 1.0], [1.0, -2.0, -3.0], [-1.0, 1.0, 2.0]];
+        let b = [-8.0, 0.0, 3.0];
+        let x = solve_linear_system(a, b).unwrap();
+
+        for (x_i, expected) in x.iter().zip([-4.0, -5.0, 2.0]) {
+            assert!((x_i - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn singular() {
+        let a = [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]];
+        assert_eq!(solve_linear_system(a, [1.0, 2.0, 3.0]), None);
+        assert_eq!(solve_linear_system([[0.0; 2]; 2], [1.0, 1.0]), None);
+        assert_eq!(
+            solve_linear_system(
+                [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+                [1.0; 3]
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn solve_badly_scaled() {
+        let x = solve_linear_system([[1e20, 0.0], [0.0, 1.0]], [1.0, 1.0]).unwrap();
+        assert_eq!(x, [1e-20, 1.0]);
+
+        let a = [[1e-12, 2e-12, 0.0], [1e6, 0.0, 1e6], [0.0, 3.0, 1.0]];
+        let x = solve_linear_system(a, [5e-12, 4e6, 9.0]).unwrap();
+        for (x_i, expected) in x.iter().zip([1.0, 2.0, 3.0]) {
+            assert!((x_i - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn solve_dyn() {
+        let a = vec![
+            vec![0.0, 2.0, 1.0],
+            vec![1.0, -2.0, -3.0],
+            vec![-1.0, 1.0, 2.0],
+        ];
+        let x = solve_linear_system_dyn(a, vec![-8.0, 0.0, 3.0]).unwrap();
+        for (x_i, expected) in x.iter().zip([-4.0, -5.0, 2.0]) {
+            assert!((x_i - expected).abs() < 1e-12);
+        }
+
+        assert_eq!(
+            solve_linear_system_dyn(vec![vec![1.0, 2.0]], vec![1.0, 2.0]),
+            None
+        );
+        assert_eq!(
+            solve_linear_system_dyn(vec![vec![1.0, 2.0], vec![2.0, 4.0]], vec![1.0, 2.0]),
+            None
+        );
+    }
+
+    #[test]
+    fn conjugate_gradient_spd() {
+        // the symmetric positive-definite matrix [[4, 1, 0], [1, 3, -1], [0, -1, 2]]
+        let apply_a = |x: &DVector<f64>| {
+            DVector::from(vec![
+                4.0 * x[0] + x[1],
+                x[0] + 3.0 * x[1] - x[2],
+                -x[1] + 2.0 * x[2],
+            ])
+        };
+        // the right hand side of the solution (1, -2, 3)
+        let b = DVector::from(vec![2.0, -8.0, 8.0]);
+
+        let x = conjugate_gradient(apply_a, &b, 1e-12, 10).unwrap();
+        for (x_i, expected) in x.iter().zip([1.0, -2.0, 3.0]) {
+            assert!((x_i - expected).abs() < 1e-10);
+        }
+
+        // terminates in at most 3 iterations in exact arithmetic
+        assert!(conjugate_gradient(apply_a, &b, 1e-12, 0).is_none());
+    }
+
+    #[test]
+    fn conjugate_gradient_not_positive_definite() {
+        let apply_a = |x: &DVector<f64>| -x;
+        let b = DVector::from(vec![1.0, 1.0]);
+        assert!(conjugate_gradient(apply_a, &b, 1e-12, 10).is_none());
+    }
+}
@@ -0,0 +1,159 @@
+use crate::algebraic_extensions::{AddIdentity, MulIdentity, NumericRing, NumericSemiGroup};
+use crate::linalg;
+use crate::vector_space::Vector;
+use core::ops::Mul;
+
+/// A [matrix](https://en.wikipedia.org/wiki/Matrix_(mathematics)) with `R` rows and `C` columns, stored row by row.
+///
+/// ```rust
+/// use nmbrs_algebra::{Matrix, Vector};
+///
+/// let a = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+/// assert_eq!(a * Vector::new([1.0, 1.0]), Vector::new([3.0, 7.0]));
+/// assert!((a.determinant() - -2.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix<const R: usize, const C: usize, F> {
+    m: [[F; C]; R],
+}
+
+impl<const R: usize, const C: usize, F> Matrix<R, C, F> {
+    /// Constructs the matrix from its rows.
+    pub fn new(rows: [[F; C]; R]) -> Self {
+        Self { m: rows }
+    }
+
+    /// Constructs the matrix from its entries `f(row, col)`.
+    pub fn from_fn(f: impl Fn(usize, usize) -> F) -> Self {
+        Self {
+            m: core::array::from_fn(|row| core::array::from_fn(|col| f(row, col))),
+        }
+    }
+}
+
+impl<const R: usize, const C: usize, F: Copy> Matrix<R, C, F> {
+    /// The entry in the `row` and the `col`umn, panicking if out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> F {
+        self.m[row][col]
+    }
+
+    pub fn transpose(&self) -> Matrix<C, R, F> {
+        Matrix::from_fn(|row, col| self.m[col][row])
+    }
+}
+
+impl<const N: usize, F: AddIdentity + MulIdentity + Copy> Matrix<N, N, F> {
+    pub fn identity() -> Self {
+        Self::from_fn(|row, col| if row == col { F::one() } else { F::zero() })
+    }
+}
+
+impl<const N: usize, F: NumericSemiGroup + Copy> Matrix<N, N, F> {
+    /// The [trace](https://en.wikipedia.org/wiki/Trace_(linear_algebra)), i.e. the sum of the diagonal entries.
+    pub fn trace(&self) -> F {
+        (0..N).fold(F::zero(), |acc, idx| acc + self.m[idx][idx])
+    }
+}
+
+impl<const N: usize> Matrix<N, N, f64> {
+    /// The [determinant](https://en.wikipedia.org/wiki/Determinant), computed by Gaussian elimination with partial pivoting.
+    /// As the signed product of the pivots it is zero only if a pivot vanishes exactly.
+    pub fn determinant(&self) -> f64 {
+        let mut m = self.m;
+        linalg::determinant(&mut m)
+    }
+}
+
+impl<const R: usize, const C: usize, F> From<[[F; C]; R]> for Matrix<R, C, F> {
+    fn from(rows: [[F; C]; R]) -> Self {
+        Self::new(rows)
+    }
+}
+
+impl<const R: usize, const C: usize, F> From<Matrix<R, C, F>> for [[F; C]; R] {
+    fn from(matrix: Matrix<R, C, F>) -> Self {
+        matrix.m
+    }
+}
+
+impl<const R: usize, const C: usize, F: NumericRing + Copy> Mul<Vector<C, F>> for Matrix<R, C, F> {
+    type Output = Vector<R, F>;
+
+    fn mul(self, rhs: Vector<C, F>) -> Self::Output {
+        let v: [F; C] = rhs.into();
+        Vector::from_fn(|row| {
+            self.m[row]
+                .iter()
+                .zip(v.iter())
+                .fold(F::zero(), |acc, (a, x)| acc + *a * *x)
+        })
+    }
+}
+
+impl<const R: usize, const C: usize, const K: usize, F: NumericRing + Copy> Mul<Matrix<C, K, F>>
+    for Matrix<R, C, F>
+{
+    type Output = Matrix<R, K, F>;
+
+    fn mul(self, rhs: Matrix<C, K, F>) -> Self::Output {
+        Matrix::from_fn(|row, col| {
+            (0..C).fold(F::zero(), |acc, idx| {
+                acc + self.m[row][idx] * rhs.m[idx][col]
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Matrix;
+    use crate::Vector;
+
+    #[test]
+    fn identity() {
+        let a = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(Matrix::<2, 2, f64>::identity() * a, a);
+        assert_eq!(a * Matrix::<3, 3, f64>::identity(), a);
+
+        let v = Vector::new([1.0, -2.0, 0.5]);
+        assert_eq!(Matrix::<3, 3, f64>::identity() * v, v);
+        assert_eq!(Matrix::<3, 3, i32>::identity().trace(), 3);
+    }
+
+    #[test]
+    fn multiply() {
+        let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::new([[1, 0], [0, 1], [1, 1]]);
+        assert_eq!(a * b, Matrix::new([[4, 5], [10, 11]]));
+        assert_eq!(a * Vector::new([1, 0, -1]), Vector::new([-2, -2]));
+    }
+
+    #[test]
+    fn transpose() {
+        let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(a.transpose(), Matrix::new([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(a.transpose().transpose(), a);
+        assert_eq!(a.get(1, 2), 6);
+    }
+
+    #[test]
+    fn trace() {
+        let a = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+        assert_eq!(a.trace(), 5.0);
+    }
+
+    #[test]
+    fn determinant() {
+        assert!((Matrix::new([[1.0, 2.0], [3.0, 4.0]]).determinant() - -2.0).abs() < 1e-12);
+        assert_eq!(Matrix::new([[0.0, 1.0], [1.0, 0.0]]).determinant(), -1.0);
+        assert_eq!(Matrix::new([[1.0, 2.0], [2.0, 4.0]]).determinant(), 0.0);
+        assert_eq!(Matrix::<3, 3, f64>::identity().determinant(), 1.0);
+
+        // small but non-zero
+        assert!((Matrix::new([[1e6, 0.0], [0.0, 1e-12]]).determinant() - 1e-6).abs() < 1e-18);
+        assert!((Matrix::new([[1e-8, 2e-8], [3e-8, 4e-8]]).determinant() - -2e-16).abs() < 1e-28);
+
+        let a = Matrix::new([[0.0, 2.0, 1.0], [1.0, -2.0, -3.0], [-1.0, 1.0, 2.0]]);
+        assert!((a.determinant() - 1.0).abs() < 1e-12);
+    }
+}
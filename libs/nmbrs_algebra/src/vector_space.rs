@@ -1,11 +1,12 @@
 use crate::{
-    algebraic_extensions::{AddIdentity, Inverse, NumericField},
-    NumericGroup, NumericRing,
+    algebraic_extensions::{AddIdentity, Inverse, MulIdentity, NumericField},
+    Abs, NumericGroup, NumericRing, RealField, Trigonometry,
 };
-use std::{
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
     fmt::Display,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub},
-    usize,
 };
 
 // TODO: improve on trait bounds below
@@ -32,6 +33,31 @@ where
     type Field = F;
 }
 
+/// A [normed vector space](https://en.wikipedia.org/wiki/Normed_vector_space).
+pub trait Norm: VectorSpace {
+    fn norm(&self) -> Self::Field;
+}
+
+/// The Euclidean norm.
+impl<F, const D: usize> Norm for Vector<D, F>
+where
+    F: RealField + Copy + MulAssign + AddAssign,
+{
+    fn norm(&self) -> F {
+        self.v.iter().fold(F::zero(), |acc, x| acc + *x * *x).sqrt()
+    }
+}
+
+/// The absolute value.
+impl<F> Norm for F
+where
+    F: RealField + Copy,
+{
+    fn norm(&self) -> F {
+        self.abs()
+    }
+}
+
 pub trait VectorSpaceF32: VectorSpace<Field = f32> {}
 pub trait VectorSpaceF64: VectorSpace<Field = f64> {}
 
@@ -54,9 +80,9 @@ pub struct Vector<const D: usize, F> {
 
 impl<const D: usize, F> Display for Vector<D, F>
 where
-    F: Display + std::fmt::Debug,
+    F: Display + core::fmt::Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[{:?}]", self.v)
     }
 }
@@ -65,6 +91,151 @@ impl<const D: usize, F> Vector<D, F> {
     pub fn new(v: [F; D]) -> Self {
         Self { v }
     }
+
+    /// Constructs the vector from its components `f(0), ..., f(D-1)`.
+    pub fn from_fn(f: impl Fn(usize) -> F) -> Self {
+        Self {
+            v: core::array::from_fn(f),
+        }
+    }
+
+    /// Applies `f` to each component.
+    pub fn map(self, f: impl Fn(F) -> F) -> Self {
+        Self { v: self.v.map(f) }
+    }
+}
+
+impl<const D: usize, F: Clone> Vector<D, F> {
+    /// The vector with all components equal to `value`.
+    pub fn splat(value: F) -> Self {
+        Self::from_fn(|_| value.clone())
+    }
+
+    /// Constructs the vector from the first `D` items of `xs`, padded with `fill` if `xs` is shorter.
+    pub fn from_slice_padded(xs: &[F], fill: F) -> Self {
+        Self::from_fn(|idx| xs.get(idx).unwrap_or(&fill).clone())
+    }
+
+    /// The vector of the first `M` components, where `M <= D` is asserted at compile time.
+    ///
+    /// ```compile_fail
+    /// use nmbrs_algebra::V;
+    /// let v = V![2; 1.0, 2.0].truncate::<3>();
+    /// ```
+    pub fn truncate<const M: usize>(self) -> Vector<M, F> {
+        const { assert!(M <= D, "cannot truncate to a larger dimension") };
+        Vector::from_fn(|idx| self.v[idx].clone())
+    }
+}
+
+impl<const D: usize, F: AddIdentity + Clone> Vector<D, F> {
+    pub fn zeros() -> Self {
+        Self::splat(F::zero())
+    }
+}
+
+impl<const D: usize, F: MulIdentity + Clone> Vector<D, F> {
+    pub fn ones() -> Self {
+        Self::splat(F::one())
+    }
+}
+
+impl<const D: usize, F: Copy> Vector<D, F> {
+    /// Combines the components of both vectors pairwise by `f`.
+    pub fn zip_with(self, other: Self, f: impl Fn(F, F) -> F) -> Self {
+        Self {
+            v: core::array::from_fn(|idx| f(self.v[idx], other.v[idx])),
+        }
+    }
+}
+
+impl<const D: usize, F: NumericRing + Copy> Vector<D, F> {
+    /// The [dot product](https://en.wikipedia.org/wiki/Dot_product) $\sum_i x_i y_i$.
+    pub fn dot(&self, other: &Self) -> F {
+        self.v
+            .iter()
+            .zip(other.v.iter())
+            .fold(F::zero(), |acc, (x, y)| acc + *x * *y)
+    }
+
+    /// The [linear interpolation](https://en.wikipedia.org/wiki/Linear_interpolation) $(1 - t) x + t y$,
+    /// which yields `self` for $t = 0$ and `other` for $t = 1$.
+    pub fn lerp(&self, other: &Self, t: F) -> Self {
+        self.zip_with(*other, |x, y| x + (y - x) * t)
+    }
+
+    /// The [Hadamard product](https://en.wikipedia.org/wiki/Hadamard_product_(matrices)), i.e. the componentwise product.
+    pub fn hadamard(&self, other: &Self) -> Self {
+        self.zip_with(*other, |x, y| x * y)
+    }
+}
+
+impl<const D: usize, F: NumericGroup + PartialOrd + Abs + Copy> Vector<D, F> {
+    /// Compares componentwise up to the absolute tolerance `epsilon`, i.e. $|x_i - y_i| \leq \epsilon$ for all $i$.
+    pub fn approx_eq(&self, other: &Self, epsilon: F) -> bool {
+        self.v
+            .iter()
+            .zip(other.v.iter())
+            .all(|(x, y)| (*x - *y).abs() <= epsilon)
+    }
+}
+
+impl<const D: usize, F: PartialOrd> Vector<D, F> {
+    /// The [lexicographic order](https://en.wikipedia.org/wiki/Lexicographic_order) of the components.
+    /// Returns `None` if a component is not comparable, e.g. `NaN`.
+    ///
+    /// NOTE: Vectors carry no natural order, hence this is not offered as `PartialOrd` implementation.
+    pub fn lexicographic_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.v.partial_cmp(&other.v)
+    }
+}
+
+impl<const D: usize, F: NumericField + Copy> Vector<D, F> {
+    /// The componentwise quotient, the inverse operation of the `hadamard` product.
+    ///
+    /// NOTE: Division by a zero component is not guarded and follows the semantics of `F`,
+    /// e.g. yields an infinite or `NaN` component for floats, and panics for integers.
+    pub fn hadamard_div(&self, other: &Self) -> Self {
+        self.zip_with(*other, |x, y| x / y)
+    }
+}
+
+impl<const D: usize, F: RealField + Trigonometry + Copy> Vector<D, F> {
+    /// The angle $\arccos \frac{x \cdot y}{\|x\| \|y\|}$ between two vectors, in the range $[0, \pi]$.
+    /// Returns `None` if either vector is zero.
+    pub fn angle(&self, other: &Self) -> Option<F> {
+        let norms = (self.dot(self) * other.dot(other)).sqrt();
+        let cos = F::checked_div(self.dot(other), norms)?;
+        Some(clamp_unit(cos).acos())
+    }
+
+    /// The [spherical linear interpolation](https://en.wikipedia.org/wiki/Slerp)
+    /// $\frac{\sin((1 - t) \Omega)}{\sin \Omega} x + \frac{\sin(t \Omega)}{\sin \Omega} y$ along the great circle
+    /// through the _unit_ vectors $x$ and $y$ enclosing the angle $\Omega$, at constant angular velocity.
+    ///
+    /// NOTE: The inputs are assumed to be normalized, which is not checked. For (nearly) parallel vectors
+    /// this falls back to `lerp`, while for opposite vectors the great circle is not unique.
+    pub fn slerp(&self, other: &Self, t: F) -> Self {
+        let omega = clamp_unit(self.dot(other)).acos();
+        let sin_omega = omega.sin();
+        if sin_omega == F::zero() {
+            return self.lerp(other, t);
+        }
+        let a = ((F::one() - t) * omega).sin() / sin_omega;
+        let b = (t * omega).sin() / sin_omega;
+        self.zip_with(*other, |x, y| x * a + y * b)
+    }
+}
+
+/// Clamps `x` to $[-1, 1]$, the domain of $\arccos$, against rounding errors.
+fn clamp_unit<F: RealField>(x: F) -> F {
+    if x > F::one() {
+        F::one()
+    } else if x < -F::one() {
+        -F::one()
+    } else {
+        x
+    }
 }
 
 impl<const D: usize, F> From<[F; D]> for Vector<D, F> {
@@ -73,8 +244,81 @@ impl<const D: usize, F> From<[F; D]> for Vector<D, F> {
     }
 }
 
+impl<const D: usize, F> From<Vector<D, F>> for [F; D] {
+    fn from(v: Vector<D, F>) -> Self {
+        v.v
+    }
+}
+
+/// The error when constructing a `Vector<D, F>` from a number of components other than `D`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DimensionMismatch {
+    pub expected: usize,
+    pub actual: usize,
+}
+
+impl Display for DimensionMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "expected {} components, got {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DimensionMismatch {}
+
+impl<const D: usize, F: Clone> TryFrom<&[F]> for Vector<D, F> {
+    type Error = DimensionMismatch;
+
+    fn try_from(xs: &[F]) -> Result<Self, Self::Error> {
+        if xs.len() != D {
+            return Err(DimensionMismatch {
+                expected: D,
+                actual: xs.len(),
+            });
+        }
+        Ok(Self::from_fn(|idx| xs[idx].clone()))
+    }
+}
+
+impl<const D: usize, F> Vector<D, F> {
+    /// Collects exactly `D` components from `iter`, failing if it yields fewer or more items.
+    pub fn try_from_iter(iter: impl IntoIterator<Item = F>) -> Result<Self, DimensionMismatch> {
+        let xs: Vec<F> = iter.into_iter().collect();
+        let actual = xs.len();
+        let v: [F; D] = xs.try_into().map_err(|_| DimensionMismatch {
+            expected: D,
+            actual,
+        })?;
+        Ok(Self { v })
+    }
+}
+
 impl<const D: usize, F> Copy for Vector<D, F> where F: Copy {}
 
+#[cfg(feature = "approx")]
+impl<const D: usize, F> approx::AbsDiffEq for Vector<D, F>
+where
+    F: approx::AbsDiffEq,
+    F::Epsilon: Copy,
+{
+    type Epsilon = F::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        F::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.v
+            .iter()
+            .zip(other.v.iter())
+            .all(|(x, y)| x.abs_diff_eq(y, epsilon))
+    }
+}
+
 impl<const D: usize, F: NumericGroup + Copy> AddIdentity for Vector<D, F> {
     fn zero() -> Self {
         [F::zero(); D].into()
@@ -131,16 +375,38 @@ impl<const D: usize, F: NumericRing + MulAssign + Copy> Mul<F> for Vector<D, F>
 
 /// Convenicence syntax.
 ///
-/// Write `V![3; 1.1, 2.2, 3.3]` for the $3$-dimensional vector `[1.1, 2.2, 3.3]`.
+/// Write `V![3; 1.1, 2.2, 3.3]` for the $3$-dimensional vector `[1.1, 2.2, 3.3]` over `f64`.
+/// Other fields can be specified explicitly, as in `V![f32; 2; 1.0, 2.0]`,
+/// or are inferred from the components if the dimension is omitted, as in `V![1_i32, 2, 3]`.
+///
+/// ```rust
+/// use nmbrs_algebra::{Vector, V};
+///
+/// let v: Vector<3, f64> = V![3; 1.1, 2.2, 3.3];
+/// assert_eq!(v, Vector::new([1.1, 2.2, 3.3]));
+///
+/// let v: Vector<2, f32> = V![f32; 2; 1.0, 2.0];
+/// assert_eq!(v + V![f32; 2; 1.0, 1.0], V![f32; 2; 2.0, 3.0]);
+///
+/// let v: Vector<3, i32> = V![1_i32, 2, 3];
+/// assert_eq!(v, Vector::new([1, 2, 3]));
+/// ```
+#[macro_export]
 macro_rules! V {
-    ( $d:expr; $( $x:expr ), +  ) => {
-        Vector::<$d, f64>::new([ $ ( $x ) , + ])
+    ( $d:expr; $( $x:expr ),+ $(,)? ) => {
+        $crate::Vector::<$d, f64>::new([ $( $x ),+ ])
+    };
+    ( $t:ty; $d:expr; $( $x:expr ),+ $(,)? ) => {
+        $crate::Vector::<$d, $t>::new([ $( $x ),+ ])
+    };
+    ( $( $x:expr ),+ $(,)? ) => {
+        $crate::Vector::new([ $( $x ),+ ])
     };
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Vector;
+    use super::{DimensionMismatch, Vector};
 
     #[test]
     fn add() {
@@ -198,4 +464,231 @@ mod tests {
 
         assert_eq!(V![2; 2.0, 3.0] * 2.0, V![2; 4.0, 6.0]);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn norm() {
+        use super::Norm;
+
+        assert_eq!(V![2; 3.0, -4.0].norm(), 5.0);
+        assert_eq!(Vector::<3, f32>::new([0.0, 0.0, 0.0]).norm(), 0.0);
+        assert_eq!((-2.5_f64).norm(), 2.5);
+    }
+
+    #[test]
+    fn map() {
+        assert_eq!(V![3; 1.0, -2.0, 0.0].map(|x| -x), -V![3; 1.0, -2.0, 0.0]);
+        assert_eq!(V![2; 1.0, 4.0].map(f64::sqrt), V![2; 1.0, 2.0]);
+    }
+
+    #[test]
+    fn zip_with() {
+        assert_eq!(
+            V![3; 1.0, 5.0, -2.0].zip_with(V![3; 3.0, 2.0, -1.0], f64::max),
+            V![3; 3.0, 5.0, -1.0]
+        );
+        assert_eq!(
+            V![2; 1.0, 2.0].zip_with(V![2; 3.0, 4.0], |x, y| x * y),
+            V![2; 3.0, 8.0]
+        );
+    }
+
+    #[test]
+    fn dot() {
+        assert_eq!(V![3; 1.0, 2.0, 3.0].dot(&V![3; -1.0, 0.5, 2.0]), 6.0);
+        assert_eq!(V![1_i32, 0].dot(&V![0, 1]), 0);
+    }
+
+    #[test]
+    fn lerp() {
+        let x = V![3; 1.0, -2.0, 0.0];
+        let y = V![3; 3.0, 2.0, 1.0];
+        assert_eq!(x.lerp(&y, 0.0), x);
+        assert_eq!(x.lerp(&y, 1.0), y);
+        assert_eq!(x.lerp(&y, 0.5), V![3; 2.0, 0.0, 0.5]);
+        assert_eq!(x.lerp(&y, 2.0), V![3; 5.0, 6.0, 2.0]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn angle() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        let x = V![2; 1.0, 0.0];
+        assert_eq!(x.angle(&V![2; 0.0, 3.0]), Some(FRAC_PI_2));
+        assert!((x.angle(&V![2; 2.0, 2.0]).unwrap() - FRAC_PI_4).abs() < 1e-15);
+        assert_eq!(x.angle(&-x), Some(PI));
+        assert_eq!(x.angle(&x), Some(0.0));
+        assert_eq!(x.angle(&V![2; 0.0, 0.0]), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn slerp() {
+        use std::f64::consts::FRAC_1_SQRT_2;
+
+        let x = V![2; 1.0, 0.0];
+        let y = V![2; 0.0, 1.0];
+        assert_eq!(x.slerp(&y, 0.0), x);
+        assert!(x.slerp(&y, 1.0).approx_eq(&y, 1e-15));
+
+        // in contrast to `lerp`, the interpolation remains on the unit circle
+        let mid = x.slerp(&y, 0.5);
+        assert!(mid.approx_eq(&V![2; FRAC_1_SQRT_2, FRAC_1_SQRT_2], 1e-15));
+        for t in [0.1, 0.3, 0.7] {
+            assert!((x.slerp(&y, t).dot(&x.slerp(&y, t)) - 1.0).abs() < 1e-14);
+            assert!((x.angle(&x.slerp(&y, t)).unwrap() - t * x.angle(&y).unwrap()).abs() < 1e-14);
+        }
+
+        // parallel vectors fall back to `lerp`
+        assert_eq!(x.slerp(&x, 0.3), x);
+    }
+
+    #[test]
+    fn hadamard() {
+        assert_eq!(V![2; 2.0, 3.0].hadamard(&V![2; 4.0, 5.0]), V![2; 8.0, 15.0]);
+        assert_eq!(
+            Vector::<3, i32>::new([1, -2, 3]).hadamard(&Vector::new([0, 2, 3])),
+            Vector::new([0, -4, 9])
+        );
+    }
+
+    #[test]
+    fn hadamard_div() {
+        assert_eq!(
+            V![2; 8.0, 15.0].hadamard_div(&V![2; 4.0, 5.0]),
+            V![2; 2.0, 3.0]
+        );
+
+        let v = V![2; 1.0, 0.0].hadamard_div(&V![2; 0.0, 0.0]);
+        let [x, y]: [f64; 2] = v.into();
+        assert_eq!(x, f64::INFINITY);
+        assert!(y.is_nan());
+    }
+
+    #[test]
+    fn constructors() {
+        assert_eq!(Vector::<3, f64>::zeros(), V![3; 0.0, 0.0, 0.0]);
+        assert_eq!(Vector::<2, f64>::ones(), V![2; 1.0, 1.0]);
+        assert_eq!(Vector::<2, i32>::splat(7), Vector::new([7, 7]));
+        assert_eq!(
+            Vector::<4, usize>::from_fn(|idx| idx * idx),
+            Vector::new([0, 1, 4, 9])
+        );
+    }
+
+    #[test]
+    fn basis_vector() {
+        let e_1 = Vector::<3, f64>::from_fn(|idx| if idx == 1 { 1.0 } else { 0.0 });
+        assert_eq!(e_1, V![3; 0.0, 1.0, 0.0]);
+        assert_eq!(e_1 * 2.0 + Vector::ones(), V![3; 1.0, 3.0, 1.0]);
+    }
+
+    #[test]
+    fn macro_field_types() {
+        assert_eq!(V![f32; 2; 1.0, 2.0], Vector::<2, f32>::new([1.0, 2.0]));
+        assert_eq!(V![i64; 3; 1, 2, 3], Vector::<3, i64>::new([1, 2, 3]));
+        assert_eq!(V![1.0_f32, 2.0], Vector::<2, f32>::new([1.0, 2.0]));
+        assert_eq!(V![2; 1.0, 2.0,], V![1.0, 2.0]);
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let xs = vec![1.0, 2.0, 3.0];
+        let v: Result<Vector<3, f64>, _> = xs.as_slice().try_into();
+        assert_eq!(v, Ok(V![3; 1.0, 2.0, 3.0]));
+
+        let v: Result<Vector<2, f64>, _> = xs.as_slice().try_into();
+        assert_eq!(
+            v,
+            Err(DimensionMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn try_from_iter() {
+        assert_eq!(
+            Vector::<3, i32>::try_from_iter(1..4),
+            Ok(Vector::new([1, 2, 3]))
+        );
+        assert_eq!(
+            Vector::<3, i32>::try_from_iter(1..3),
+            Err(DimensionMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            Vector::<3, i32>::try_from_iter(1..5),
+            Err(DimensionMismatch {
+                expected: 3,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn approx_eq() {
+        let v = V![3; 0.1, 0.2, 0.3];
+        let w = v + V![3; 1e-16, 0.0, -1e-16];
+        assert_ne!(v, w);
+        assert!(v.approx_eq(&w, 1e-15));
+        assert!(!v.approx_eq(&V![3; 0.1, 0.2, 0.4], 1e-15));
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn abs_diff_eq() {
+        approx::assert_abs_diff_eq!(V![2; 1.0, 2.0], V![2; 1.0 + 1e-15, 2.0], epsilon = 1e-14);
+        approx::assert_abs_diff_ne!(V![2; 1.0, 2.0], V![2; 1.1, 2.0]);
+    }
+
+    #[test]
+    fn from_slice_padded() {
+        assert_eq!(
+            Vector::<4, f64>::from_slice_padded(&[1.0, 2.0], 0.0),
+            V![4; 1.0, 2.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            Vector::<2, f64>::from_slice_padded(&[1.0, 2.0, 3.0], 0.0),
+            V![2; 1.0, 2.0]
+        );
+        assert_eq!(
+            Vector::<2, f64>::from_slice_padded(&[], -1.0),
+            V![2; -1.0, -1.0]
+        );
+    }
+
+    #[test]
+    fn truncate() {
+        let v = V![3; 1.0, 2.0, 3.0];
+        assert_eq!(v.truncate::<2>(), V![2; 1.0, 2.0]);
+        assert_eq!(v.truncate::<3>(), v);
+        assert_eq!(v.truncate::<0>(), Vector::<0, f64>::new([]));
+    }
+
+    #[test]
+    fn lexicographic_cmp() {
+        use core::cmp::Ordering;
+
+        assert_eq!(
+            V![2; 1.0, 3.0].lexicographic_cmp(&V![2; 2.0, 0.0]),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            V![2; 1.0, 3.0].lexicographic_cmp(&V![2; 1.0, 2.0]),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            V![2; 1.0, 3.0].lexicographic_cmp(&V![2; 1.0, 3.0]),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            V![2; f64::NAN, 3.0].lexicographic_cmp(&V![2; 1.0, 3.0]),
+            None
+        );
+    }
 }
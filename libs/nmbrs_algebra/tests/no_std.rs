@@ -0,0 +1,21 @@
+//! Builds against `core` only, run with `cargo test -p nmbrs_algebra --no-default-features`
+//! to check that the crate stays usable without `std`.
+#![no_std]
+
+use nmbrs_algebra::{Matrix, Vector};
+
+#[test]
+fn construct_and_add_vectors() {
+    let v = Vector::new([1.0, 2.0, 3.0]);
+    let w = Vector::from_fn(|idx| idx as f64);
+
+    assert_eq!(v + w, Vector::new([1.0, 3.0, 5.0]));
+    assert_eq!((v - w) * 2.0, Vector::new([2.0, 2.0, 2.0]));
+    assert_eq!(v.hadamard(&w), Vector::new([0.0, 2.0, 6.0]));
+}
+
+#[test]
+fn matrix_vector_product() {
+    let a = Matrix::new([[1, 2], [3, 4]]);
+    assert_eq!(a * Vector::new([1, -1]), Vector::new([-1, -1]));
+}
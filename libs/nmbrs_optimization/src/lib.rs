@@ -1,4 +1,6 @@
 pub mod ode_solvers;
 pub mod root_finder;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_support;
 
 extern crate nmbrs_algebra;
@@ -0,0 +1,148 @@
+use super::{try_integrate_by, OdeSolver, OdeSystem, Rk4Solver, TimeState};
+use nmbrs_algebra::VectorSpace;
+use std::collections::VecDeque;
+
+/// The explicit [Adams-Bashforth methods](https://en.wikipedia.org/wiki/Linear_multistep_method#Adams%E2%80%93Bashforth_methods)
+/// reuse the slopes of the previous steps, requiring only a single evaluation of the system per step.
+///
+/// As the method requires a history of states, the first steps are bootstrapped with the `Rk4Solver`.
+pub enum AdamsBashforthSolver {
+    /// The two-step method of order 2.
+    TwoStep,
+    /// The four-step method of order 4.
+    FourStep,
+}
+
+impl AdamsBashforthSolver {
+    pub fn n_steps(&self) -> usize {
+        match self {
+            Self::TwoStep => 2,
+            Self::FourStep => 4,
+        }
+    }
+
+    /// The coefficients for the slopes, starting with the most recent one, and their common denominator.
+    fn coefficients(&self) -> (&'static [i32], i32) {
+        match self {
+            Self::TwoStep => (&[3, -1], 2),
+            Self::FourStep => (&[55, -59, 37, -9], 24),
+        }
+    }
+
+    /// Advances the state given the slopes at the `n_steps` most recent states, starting with the most recent one.
+    pub fn step<V>(&self, state: &TimeState<V>, slopes: &VecDeque<V>, dt: V::Field) -> TimeState<V>
+    where
+        V: VectorSpace + Clone,
+        V::Field: Clone + From<i32>,
+    {
+        let (coefficients, denominator) = self.coefficients();
+
+        let weighted_slope = slopes
+            .iter()
+            .zip(coefficients.iter())
+            .map(|(k, c)| k.clone() * V::Field::from(*c))
+            .fold(V::zero(), |acc, k| acc + k);
+
+        let dt_scaled = dt.clone() / V::Field::from(denominator);
+        TimeState {
+            t: state.t.clone() + dt,
+            y: state.y.clone() + weighted_slope * dt_scaled,
+        }
+    }
+}
+
+impl<S, V> OdeSolver<S, V> for AdamsBashforthSolver
+where
+    S: OdeSystem<V>,
+    V: VectorSpace + Clone,
+    V::Field: Clone + PartialOrd + From<i32>,
+{
+    fn integrate_checked<E>(
+        &self,
+        f: &S,
+        initial_state: TimeState<V>,
+        t_end: V::Field,
+        n: usize,
+        mut check: impl FnMut() -> Result<(), E>,
+    ) -> Result<Vec<TimeState<V>>, E> {
+        let n_steps = self.n_steps();
+
+        // the slopes of the most recent states, the latest in front
+        let mut slopes: VecDeque<V> = VecDeque::with_capacity(n_steps);
+
+        try_integrate_by(initial_state, t_end, n, |state, dt| {
+            slopes.push_front(f(state));
+            slopes.truncate(n_steps);
+
+            // bootstrap until the history of slopes is complete
+            let next_state = if slopes.len() < n_steps {
+                Rk4Solver.step(f, state, dt)
+            } else {
+                self.step(state, &slopes, dt)
+            };
+            check()?;
+            Ok(next_state)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AdamsBashforthSolver;
+    use crate::ode_solvers::{OdeSolver, TimeState};
+
+    fn assert_convergence(solver: AdamsBashforthSolver, order: i32, constant: f64) {
+        // initial value problem
+        let f = |s: &TimeState<f64>| s.y * s.t.sin();
+        let initial_state = TimeState { t: 0.0, y: -1.0 };
+
+        // solution
+        let sol = |t: f64| -(1.0 - t.cos()).exp();
+
+        let t_end = 10.0;
+
+        for k in 5..15 {
+            let n = 2_usize.pow(k);
+            let ys = solver.integrate(&f, initial_state.clone(), t_end, n);
+            assert_eq!(ys.len(), n + 1);
+
+            let dt: f64 = t_end / n as f64;
+            let upper_bound = constant * dt.powi(order);
+
+            for s_i in ys.iter() {
+                let err_i = (sol(s_i.t) - s_i.y).abs();
+                assert!(
+                    err_i <= upper_bound,
+                    "error {} exceeded threshold {} ({})",
+                    err_i,
+                    upper_bound,
+                    n
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn adams_bashforth_two_step_convergence() {
+        assert_convergence(AdamsBashforthSolver::TwoStep, 2, 20.0);
+    }
+
+    #[test]
+    fn adams_bashforth_four_step_convergence() {
+        assert_convergence(AdamsBashforthSolver::FourStep, 4, 100.0);
+    }
+
+    #[test]
+    fn adams_bashforth_few_steps() {
+        let f = |s: &TimeState<f64>| s.y;
+        let initial_state = TimeState { t: 0.0, y: 1.0 };
+
+        // only bootstrapping steps
+        let ys = AdamsBashforthSolver::FourStep.integrate(&f, initial_state.clone(), 1.0, 2);
+        assert_eq!(ys.len(), 3);
+
+        assert!(AdamsBashforthSolver::TwoStep
+            .integrate(&f, initial_state, -1.0, 10)
+            .is_empty());
+    }
+}
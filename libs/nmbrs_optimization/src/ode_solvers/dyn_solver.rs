@@ -0,0 +1,98 @@
+use crate::ode_solvers::{
+    integrate_by, EulerSolver, OdeStepSolver, Rk2Solver, Rk4Solver, TimeState,
+};
+use nmbrs_algebra::VectorSpace;
+
+/// An `OdeSystem` behind a trait object, as consumed by the `DynOdeStepSolver`.
+pub type DynOdeSystem<'a, V> = &'a dyn Fn(&TimeState<V>) -> V;
+
+/// An object-safe counterpart of the `OdeStepSolver`, which allows to select a solver at runtime
+/// and to store it as `Box<dyn DynOdeStepSolver<V>>`, see `solver_by_name`.
+pub trait DynOdeStepSolver<V>
+where
+    V: VectorSpace + Clone,
+    V::Field: Clone + PartialOrd + From<i32>,
+{
+    fn solve_step_dyn(
+        &self,
+        f: DynOdeSystem<V>,
+        state: &TimeState<V>,
+        dt: V::Field,
+    ) -> TimeState<V>;
+
+    /// Integrates with `n` equidistant steps as `integrate`.
+    fn integrate_dyn(
+        &self,
+        f: DynOdeSystem<V>,
+        initial_state: TimeState<V>,
+        t_end: V::Field,
+        n: usize,
+    ) -> Vec<TimeState<V>> {
+        integrate_by(initial_state, t_end, n, |state, dt| {
+            self.solve_step_dyn(f, state, dt)
+        })
+    }
+}
+
+macro_rules! impl_dyn_ode_step_solver {
+    ($solver:ty) => {
+        impl<V> DynOdeStepSolver<V> for $solver
+        where
+            V: VectorSpace + Clone,
+            V::Field: Clone + PartialOrd + From<i32>,
+        {
+            fn solve_step_dyn(
+                &self,
+                f: DynOdeSystem<V>,
+                state: &TimeState<V>,
+                dt: V::Field,
+            ) -> TimeState<V> {
+                self.solve_step(&f, state, dt)
+            }
+        }
+    };
+}
+
+impl_dyn_ode_step_solver! { EulerSolver }
+impl_dyn_ode_step_solver! { Rk2Solver }
+impl_dyn_ode_step_solver! { Rk4Solver }
+
+/// Selects a one-step solver by its (case-insensitive) name, one of `euler`, `rk2` and `rk4`.
+pub fn solver_by_name(name: &str) -> Option<Box<dyn DynOdeStepSolver<f64>>> {
+    match name.to_lowercase().as_str() {
+        "euler" => Some(Box::new(EulerSolver)),
+        "rk2" => Some(Box::new(Rk2Solver)),
+        "rk4" => Some(Box::new(Rk4Solver)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ode_solvers::{EulerSolver, OdeSolver, Rk2Solver, Rk4Solver, TimeState};
+
+    #[test]
+    fn solver_by_name() {
+        let f = |s: &TimeState<f64>| s.y * s.t.sin();
+        let initial_state = TimeState { t: 0.0, y: -1.0 };
+        let (t_end, n) = (10.0, 100);
+
+        let expected = [
+            EulerSolver.integrate(&f, initial_state.clone(), t_end, n),
+            Rk2Solver.integrate(&f, initial_state.clone(), t_end, n),
+            Rk4Solver.integrate(&f, initial_state.clone(), t_end, n),
+        ];
+
+        for (name, expected) in ["euler", "rk2", "RK4"].iter().zip(expected.iter()) {
+            let solver = super::solver_by_name(name).unwrap();
+            let ys = solver.integrate_dyn(&f, initial_state.clone(), t_end, n);
+            assert_eq!(ys.len(), n + 1);
+            for (y, e) in ys.iter().zip(expected.iter()) {
+                assert_eq!(y.t, e.t);
+                assert_eq!(y.y, e.y);
+            }
+        }
+
+        assert!(super::solver_by_name("rk3").is_none());
+    }
+}
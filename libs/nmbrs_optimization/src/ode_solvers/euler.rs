@@ -1,4 +1,4 @@
-use crate::ode_solvers::{OdeStepSolver, OdeSystem, TimeState};
+use crate::ode_solvers::{OdeStepSolver, OdeSystem, OneStepMethod, TimeState};
 use nmbrs_algebra::VectorSpace;
 
 /// The [Euler Method](https://en.wikipedia.org/wiki/Euler_method)
@@ -19,6 +19,8 @@ impl EulerSolver {
     }
 }
 
+impl OneStepMethod for EulerSolver {}
+
 impl<S, V> OdeStepSolver<S, V> for EulerSolver
 where
     S: OdeSystem<V>,
@@ -53,8 +55,7 @@ mod tests {
             let h = t_end / n as f64;
             let upper_bound = 20.0 * h;
 
-            for i in 0..n {
-                let s_i = &ys[i];
+            for s_i in ys.iter().take(n) {
                 let sol_i = sol(s_i.t);
                 let err_i = (sol_i - s_i.y).abs();
                 assert!(
@@ -0,0 +1,115 @@
+//! Classic initial value problems with known closed-form solutions,
+//! e.g. for convergence tests and documentation examples.
+//!
+//! ```rust
+//! use nmbrs_optimization::ode_solvers::{examples, OdeSolver, Rk4Solver};
+//!
+//! let problem = examples::exponential_decay(0.5);
+//! let states = Rk4Solver.integrate(&problem.system, problem.initial_state.clone(), 2.0, 20);
+//! let last = states.last().unwrap();
+//! assert!((last.y - (problem.solution)(last.t)).abs() < 1e-6);
+//! ```
+use crate::ode_solvers::TimeState;
+use nmbrs_algebra::{Vector, VectorSpace};
+
+/// The right-hand side $f(t, y)$ of an `AnalyticProblem`.
+pub type BoxedOdeSystem<V> = Box<dyn Fn(&TimeState<V>) -> V>;
+
+/// An initial value problem $y' = f(t, y)$, $y(t_0) = y_0$ together with its exact solution $y(t)$.
+pub struct AnalyticProblem<V>
+where
+    V: VectorSpace,
+{
+    pub system: BoxedOdeSystem<V>,
+    pub solution: Box<dyn Fn(V::Field) -> V>,
+    pub initial_state: TimeState<V>,
+}
+
+/// [Exponential decay](https://en.wikipedia.org/wiki/Exponential_decay) $y' = -\lambda y$ with $y(0) = 1$,
+/// solved by $y(t) = e^{-\lambda t}$.
+pub fn exponential_decay(rate: f64) -> AnalyticProblem<f64> {
+    AnalyticProblem {
+        system: Box::new(move |s: &TimeState<f64>| -rate * s.y),
+        solution: Box::new(move |t: f64| (-rate * t).exp()),
+        initial_state: TimeState { t: 0.0, y: 1.0 },
+    }
+}
+
+/// [Logistic growth](https://en.wikipedia.org/wiki/Logistic_function#Logistic_differential_equation)
+/// $y' = r y (1 - y / K)$ with $y(0) = y_0$, solved by $y(t) = \frac{K y_0}{y_0 + (K - y_0) e^{-r t}}$.
+pub fn logistic_growth(rate: f64, capacity: f64, y0: f64) -> AnalyticProblem<f64> {
+    AnalyticProblem {
+        system: Box::new(move |s: &TimeState<f64>| rate * s.y * (1.0 - s.y / capacity)),
+        solution: Box::new(move |t: f64| {
+            capacity * y0 / (y0 + (capacity - y0) * (-rate * t).exp())
+        }),
+        initial_state: TimeState { t: 0.0, y: y0 },
+    }
+}
+
+/// The [harmonic oscillator](https://en.wikipedia.org/wiki/Harmonic_oscillator) $x'' = -\omega^2 x$
+/// as the first-order system $(x, v)' = (v, -\omega^2 x)$ with $(x, v)(0) = (1, 0)$,
+/// solved by $(x, v)(t) = (\cos \omega t, -\omega \sin \omega t)$.
+pub fn harmonic_oscillator(omega: f64) -> AnalyticProblem<Vector<2, f64>> {
+    AnalyticProblem {
+        system: Box::new(move |s: &TimeState<Vector<2, f64>>| {
+            let [x, v]: [f64; 2] = s.y.into();
+            Vector::new([v, -omega * omega * x])
+        }),
+        solution: Box::new(move |t: f64| {
+            Vector::new([(omega * t).cos(), -omega * (omega * t).sin()])
+        }),
+        initial_state: TimeState {
+            t: 0.0,
+            y: Vector::new([1.0, 0.0]),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{exponential_decay, harmonic_oscillator, logistic_growth};
+    use crate::ode_solvers::{EulerSolver, OdeSolver, Rk4Solver};
+    use crate::test_support::assert_time_series_close;
+    use nmbrs_algebra::Norm;
+
+    #[test]
+    fn exponential_decay_rk4() {
+        let problem = exponential_decay(1.5);
+        let states = Rk4Solver.integrate(&problem.system, problem.initial_state, 4.0, 200);
+        assert_time_series_close(&states, problem.solution, 1e-8);
+    }
+
+    #[test]
+    fn exponential_decay_euler_convergence() {
+        let problem = exponential_decay(1.0);
+        let error = |n: usize| {
+            let states =
+                EulerSolver.integrate(&problem.system, problem.initial_state.clone(), 1.0, n);
+            let last = states.last().unwrap();
+            (last.y - (problem.solution)(last.t)).abs()
+        };
+
+        // Euler is of first order, hence doubling the number of steps halves the error
+        let ratio = error(100) / error(200);
+        assert!(ratio > 1.9 && ratio < 2.1);
+    }
+
+    #[test]
+    fn logistic_growth_rk4() {
+        let problem = logistic_growth(2.0, 10.0, 0.5);
+        let states = Rk4Solver.integrate(&problem.system, problem.initial_state, 5.0, 200);
+        assert_time_series_close(&states, problem.solution, 1e-6);
+        // approaches the capacity
+        assert!((states.last().unwrap().y - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn harmonic_oscillator_rk4() {
+        let problem = harmonic_oscillator(2.0);
+        let states = Rk4Solver.integrate(&problem.system, problem.initial_state, 10.0, 1_000);
+        for s in states {
+            assert!((s.y - (problem.solution)(s.t)).norm() < 1e-6);
+        }
+    }
+}
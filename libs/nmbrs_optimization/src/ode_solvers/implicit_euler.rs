@@ -0,0 +1,167 @@
+use crate::ode_solvers::{try_equidistant_step, OdeSystem, TimeState};
+use crate::root_finder::{from_count, steffensen, RootFinderConfig, RootFinderField};
+use std::fmt::{Debug, Display};
+
+/// The error of an integration which could not be completed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntegrationError<T> {
+    /// The inner solver of an implicit method did not converge within its configured iterations
+    /// for the `step`-th step, which was to advance the solution to `time`.
+    InnerSolverDiverged { step: usize, time: T },
+}
+
+impl<T: Display> Display for IntegrationError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InnerSolverDiverged { step, time } => write!(
+                f,
+                "inner solver diverged in step {} towards time {}",
+                step, time
+            ),
+        }
+    }
+}
+
+impl<T: Debug + Display> std::error::Error for IntegrationError<T> {}
+
+/// The [implicit (or backward) Euler method](https://en.wikipedia.org/wiki/Backward_Euler_method)
+/// $ y_{n+1} = y_n + dt f(t_{n+1}, y_{n+1}) $ for scalar ODEs, which remains stable for stiff problems.
+///
+/// The implicit equation of each step is solved by the derivative-free `steffensen` method,
+/// starting from the explicit Euler prediction and configured by `inner_config`.
+pub struct ImplicitEulerSolver<T = f64> {
+    pub inner_config: RootFinderConfig<T>,
+}
+
+impl<T: RootFinderField> Default for ImplicitEulerSolver<T> {
+    fn default() -> Self {
+        Self {
+            inner_config: RootFinderConfig::default(),
+        }
+    }
+}
+
+impl<T> ImplicitEulerSolver<T>
+where
+    T: RootFinderField,
+{
+    pub fn new(inner_config: RootFinderConfig<T>) -> Self {
+        Self { inner_config }
+    }
+
+    /// Advances the state by `dt`, or returns `None` if the inner solver does not converge.
+    pub fn step<S>(&self, f: &S, state: &TimeState<T>, dt: T) -> Option<TimeState<T>>
+    where
+        S: OdeSystem<T>,
+    {
+        let t = state.t + dt;
+        let y = state.y;
+        let residual = |y_next: T| y_next - y - dt * f(&TimeState { t, y: y_next });
+        let prediction = y + dt * f(state);
+
+        let y_next = steffensen(residual, prediction, Some(self.inner_config.clone()))?;
+        Some(TimeState { t, y: y_next })
+    }
+
+    /// Integrates with `n` equidistant steps as `integrate`, but fails with the step
+    /// for which the inner solver did not converge.
+    pub fn integrate<S>(
+        &self,
+        f: &S,
+        initial_state: TimeState<T>,
+        t_end: T,
+        n: usize,
+    ) -> Result<Vec<TimeState<T>>, IntegrationError<T>>
+    where
+        S: OdeSystem<T>,
+    {
+        if t_end < initial_state.t || n < 1 {
+            return Ok(Vec::with_capacity(0));
+        }
+
+        let t0 = initial_state.t;
+        let dt = (t_end - t0) / from_count(n);
+        let mut ys = Vec::with_capacity(n + 1);
+        ys.push(initial_state);
+        // the index of the step, accumulated in the field
+        let mut index = T::zero();
+
+        for step in 1..=n {
+            index += T::one();
+            if let Some(state) = ys.last() {
+                let next_state =
+                    try_equidistant_step(&t0, &dt, &index, |dt| self.step(f, state, dt).ok_or(()))
+                        .map_err(|_| IntegrationError::InnerSolverDiverged {
+                            step,
+                            time: t0 + dt * index,
+                        })?;
+                ys.push(next_state);
+            }
+        }
+
+        Ok(ys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntegrationError;
+    use crate::ode_solvers::{EulerSolver, OdeSolver, TimeState};
+    use crate::root_finder::RootFinderConfig;
+
+    // the stiff problem y' = -50 (y - cos(t))
+    fn f(s: &TimeState<f64>) -> f64 {
+        -50.0 * (s.y - s.t.cos())
+    }
+
+    fn sol(t: f64) -> f64 {
+        (2500.0 * t.cos() + 50.0 * t.sin()) / 2501.0 + (1.0 / 2501.0) * (-50.0 * t).exp()
+    }
+
+    #[test]
+    fn implicit_euler_stiff() {
+        let initial_state = TimeState { t: 0.0, y: 1.0 };
+        let (t_end, n) = (5.0, 50);
+
+        // the explicit method is unstable for dt = 0.1
+        let ys = EulerSolver.integrate(&f, initial_state.clone(), t_end, n);
+        assert!(ys.last().unwrap().y.abs() > 1e10);
+
+        let ys = super::ImplicitEulerSolver::default()
+            .integrate(&f, initial_state, t_end, n)
+            .unwrap();
+        assert_eq!(ys.len(), n + 1);
+        for s in ys {
+            assert!((s.y - sol(s.t)).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn implicit_euler_f32() {
+        let f = |s: &TimeState<f32>| -50.0 * (s.y - s.t.cos());
+        let initial_state = TimeState { t: 0.0_f32, y: 1.0 };
+        let (t_end, n) = (5.0, 50);
+
+        let ys = super::ImplicitEulerSolver::<f32>::default()
+            .integrate(&f, initial_state, t_end, n)
+            .unwrap();
+        assert_eq!(ys.len(), n + 1);
+        assert_eq!(ys.last().unwrap().t, t_end);
+        for s in ys {
+            assert!((s.y as f64 - sol(s.t as f64)).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn implicit_euler_inner_solver_diverged() {
+        let initial_state = TimeState { t: 0.0, y: 1.0 };
+        let inner_config = RootFinderConfig::new().with_max_iterations(1);
+
+        let result =
+            super::ImplicitEulerSolver::new(inner_config).integrate(&f, initial_state, 5.0, 50);
+        assert_eq!(
+            result.unwrap_err(),
+            IntegrationError::InnerSolverDiverged { step: 1, time: 0.1 }
+        );
+    }
+}
@@ -1,9 +1,25 @@
+mod adams;
+mod dyn_solver;
 mod euler;
+pub mod examples;
+mod implicit_euler;
 mod runge_kutta;
+mod sde;
+mod verlet;
+pub use adams::AdamsBashforthSolver;
+pub use dyn_solver::{solver_by_name, DynOdeStepSolver, DynOdeSystem};
 pub use euler::EulerSolver;
-use nmbrs_algebra::VectorSpace;
+pub use implicit_euler::{ImplicitEulerSolver, IntegrationError};
+use nmbrs_algebra::{Norm, VectorSpace};
 pub use runge_kutta::{Rk2Solver, Rk4Solver};
+pub use sde::EulerMaruyamaSolver;
+use std::cell::RefCell;
+use std::convert::Infallible;
 use std::fmt::{Debug, Display};
+pub use verlet::{
+    integrate_second_order, SecondOrderState, SecondOrderStepSolver, SecondOrderSystem,
+    VerletSolver,
+};
 
 // for simplicity we assume that the domain and image of f is both V
 pub trait OdeSystem<V>: Fn(&TimeState<V>) -> V
@@ -19,6 +35,41 @@ where
 {
 }
 
+/// Adapts a closure in the explicit form $f(t, y)$ to an `OdeSystem`.
+///
+/// ```rust
+/// use nmbrs_optimization::ode_solvers::{from_ty, OdeSolver, Rk4Solver, TimeState};
+///
+/// let f = from_ty(|t: f64, y: &f64| y * t.sin());
+/// let states = Rk4Solver.integrate(&f, TimeState { t: 0.0, y: -1.0 }, 1.0, 10);
+/// assert_eq!(states.len(), 11);
+/// ```
+pub fn from_ty<V, F>(f: F) -> impl OdeSystem<V>
+where
+    V: VectorSpace,
+    V::Field: Clone,
+    F: Fn(V::Field, &V) -> V,
+{
+    move |s: &TimeState<V>| f(s.t.clone(), &s.y)
+}
+
+/// Adapts a closure $f(y)$ of an autonomous system, which does not depend on the time explicitly, to an `OdeSystem`.
+///
+/// ```rust
+/// use nmbrs_optimization::ode_solvers::{autonomous, OdeSolver, Rk4Solver, TimeState};
+///
+/// let f = autonomous(|y: &f64| -y);
+/// let states = Rk4Solver.integrate(&f, TimeState { t: 0.0, y: 1.0 }, 1.0, 10);
+/// assert_eq!(states.len(), 11);
+/// ```
+pub fn autonomous<V, F>(f: F) -> impl OdeSystem<V>
+where
+    V: VectorSpace,
+    F: Fn(&V) -> V,
+{
+    move |s: &TimeState<V>| f(&s.y)
+}
+
 // #[derive(Debug, Clone, PartialEq)]
 pub struct TimeState<V>
 where
@@ -62,13 +113,171 @@ where
     }
 }
 
-pub trait OdeStepSolver<S, V>
+/// Convenience accessors for the states returned by an integration, e.g. to feed times and values separately into a plot.
+///
+/// ```rust
+/// use nmbrs_optimization::ode_solvers::{OdeSolver, Rk4Solver, TimeState, Trajectory};
+///
+/// let f = |s: &TimeState<f64>| -s.y;
+/// let states = Rk4Solver.integrate(&f, TimeState { t: 0.0, y: 1.0 }, 1.0, 4);
+/// let (ts, ys) = states.unzip();
+/// assert_eq!(ts, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+/// assert_eq!(ys.len(), 5);
+/// assert_eq!(states.final_state().unwrap().t, 1.0);
+/// ```
+pub trait Trajectory<V>
+where
+    V: VectorSpace,
+{
+    /// The times $t_i$ of the states.
+    fn times(&self) -> Vec<V::Field>;
+
+    /// The values $y_i$ of the states.
+    fn values(&self) -> Vec<V>;
+
+    /// The times and values of the states as separate vectors.
+    fn unzip(&self) -> (Vec<V::Field>, Vec<V>);
+
+    /// The first state, if any.
+    fn initial_state(&self) -> Option<&TimeState<V>>;
+
+    /// The last state, if any.
+    fn final_state(&self) -> Option<&TimeState<V>>;
+}
+
+impl<V> Trajectory<V> for [TimeState<V>]
+where
+    V: VectorSpace + Clone,
+    V::Field: Clone,
+{
+    fn times(&self) -> Vec<V::Field> {
+        self.iter().map(|s| s.t.clone()).collect()
+    }
+
+    fn values(&self) -> Vec<V> {
+        self.iter().map(|s| s.y.clone()).collect()
+    }
+
+    fn unzip(&self) -> (Vec<V::Field>, Vec<V>) {
+        self.iter().map(|s| (s.t.clone(), s.y.clone())).unzip()
+    }
+
+    fn initial_state(&self) -> Option<&TimeState<V>> {
+        self.first()
+    }
+
+    fn final_state(&self) -> Option<&TimeState<V>> {
+        self.last()
+    }
+}
+
+/// Marks a [one-step method](https://en.wikipedia.org/wiki/Numerical_methods_for_ordinary_differential_equations#One-step_methods),
+/// which advances a state without regard to the states before.
+///
+/// Being a supertrait of the `OdeStepSolver`, multistep solvers like the `AdamsBashforthSolver`,
+/// which do not implement it, can implement the `OdeSolver` directly.
+pub trait OneStepMethod {}
+
+pub trait OdeStepSolver<S, V>: OneStepMethod
 where
     S: OdeSystem<V>,
     V: VectorSpace + Clone,
     V::Field: Clone,
 {
     fn solve_step(&self, f: &S, state: &TimeState<V>, dt: V::Field) -> TimeState<V>;
+
+    /// See `integrate_to_steady_state`.
+    fn integrate_to_steady_state(
+        &self,
+        f: &S,
+        initial_state: TimeState<V>,
+        max_steps: usize,
+        dt: V::Field,
+        tol: V::Field,
+    ) -> Vec<TimeState<V>>
+    where
+        Self: Sized,
+        V: Norm,
+        V::Field: PartialOrd + From<i32>,
+    {
+        integrate_to_steady_state(self, f, initial_state, max_steps, dt, tol)
+    }
+
+    /// See `integrate_with_error`.
+    fn integrate_with_error(
+        &self,
+        f: &S,
+        initial_state: TimeState<V>,
+        t_end: V::Field,
+        n: usize,
+    ) -> Vec<(TimeState<V>, V::Field)>
+    where
+        Self: Sized,
+        V: Norm,
+        V::Field: PartialOrd + From<i32>,
+    {
+        integrate_with_error(self, f, initial_state, t_end, n)
+    }
+
+    /// See `integrate_at`.
+    fn integrate_at(
+        &self,
+        f: &S,
+        initial_state: TimeState<V>,
+        times: &[V::Field],
+        n_substeps: usize,
+    ) -> Vec<TimeState<V>>
+    where
+        Self: Sized,
+        V::Field: PartialOrd + From<i32>,
+    {
+        integrate_at(self, f, initial_state, times, n_substeps)
+    }
+
+    /// See `integrate_with_steps`.
+    fn integrate_with_steps(
+        &self,
+        f: &S,
+        initial_state: TimeState<V>,
+        dts: &[V::Field],
+    ) -> Vec<TimeState<V>>
+    where
+        Self: Sized,
+    {
+        integrate_with_steps(self, f, initial_state, dts)
+    }
+
+    /// See `integrate_decimated`.
+    fn integrate_decimated(
+        &self,
+        f: &S,
+        initial_state: TimeState<V>,
+        t_end: V::Field,
+        n: usize,
+        keep_every: usize,
+    ) -> Vec<TimeState<V>>
+    where
+        Self: Sized,
+        V::Field: PartialOrd + From<i32>,
+    {
+        integrate_decimated(self, f, initial_state, t_end, n, keep_every)
+    }
+
+    /// See `integrate_filtered`.
+    fn integrate_filtered(
+        &self,
+        f: &S,
+        initial_state: TimeState<V>,
+        t_end: V::Field,
+        n: usize,
+        keep: impl Fn(&TimeState<V>) -> bool,
+    ) -> Vec<TimeState<V>>
+    where
+        Self: Sized,
+        V::Field: PartialOrd + From<i32>,
+    {
+        integrate_filtered(self, f, initial_state, t_end, n, keep)
+    }
 }
 
 /// [Numerical solver](https://en.wikipedia.org/wiki/Numerical_methods_for_ordinary_differential_equations)
@@ -130,7 +339,69 @@ where
         initial_state: TimeState<V>,
         t_end: V::Field,
         n: usize,
-    ) -> Vec<TimeState<V>>;
+    ) -> Vec<TimeState<V>> {
+        match self.integrate_checked(f, initial_state, t_end, n, || Ok::<_, Infallible>(())) {
+            Ok(ys) => ys,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Integrates as `integrate` while `check` succeeds after each step.
+    /// The integration stops at the first error of `check`, which is returned while the states are discarded.
+    fn integrate_checked<E>(
+        &self,
+        f: &S,
+        initial_state: TimeState<V>,
+        t_end: V::Field,
+        n: usize,
+        check: impl FnMut() -> Result<(), E>,
+    ) -> Result<Vec<TimeState<V>>, E>;
+
+    /// Integrates as `integrate`, additionally returning the step size.
+    /// Returns `None` if there are no steps, i.e. if `n` is zero or `t_end` precedes the initial time.
+    fn integrate_detailed(
+        &self,
+        f: &S,
+        initial_state: TimeState<V>,
+        t_end: V::Field,
+        n: usize,
+    ) -> Option<IntegrationOutput<V>>
+    where
+        V::Field: Clone + PartialOrd + From<i32>,
+    {
+        if t_end < initial_state.t || n < 1 {
+            return None;
+        }
+
+        let dt = (t_end.clone() - initial_state.t.clone()) / (n as i32).into();
+        Some(IntegrationOutput {
+            states: self.integrate(f, initial_state, t_end, n),
+            dt,
+        })
+    }
+
+    /// See `try_integrate_fallible`.
+    fn try_integrate_fallible<E>(
+        &self,
+        f: &dyn Fn(&TimeState<V>) -> Result<V, E>,
+        initial_state: TimeState<V>,
+        t_end: V::Field,
+        n: usize,
+    ) -> Result<Vec<TimeState<V>>, E>
+    where
+        Self: Sized + for<'a> OdeSolver<DynOdeSystem<'a, V>, V>,
+    {
+        try_integrate_fallible(self, f, initial_state, t_end, n)
+    }
+}
+
+/// The states of an integration with `n` equidistant steps of size `dt`.
+pub struct IntegrationOutput<V>
+where
+    V: VectorSpace,
+{
+    pub states: Vec<TimeState<V>>,
+    pub dt: <V as VectorSpace>::Field,
 }
 
 impl<T, S, V> OdeSolver<S, V> for T
@@ -140,14 +411,19 @@ where
     V: VectorSpace + Clone,
     V::Field: Clone + PartialOrd + From<i32>,
 {
-    fn integrate(
+    fn integrate_checked<E>(
         &self,
         f: &S,
         initial_state: TimeState<V>,
         t_end: V::Field,
         n: usize,
-    ) -> Vec<TimeState<V>> {
-        integrate(self, f, initial_state, t_end, n)
+        mut check: impl FnMut() -> Result<(), E>,
+    ) -> Result<Vec<TimeState<V>>, E> {
+        try_integrate_by(initial_state, t_end, n, |state, dt| {
+            let next_state = self.solve_step(f, state, dt);
+            check()?;
+            Ok(next_state)
+        })
     }
 }
 
@@ -163,23 +439,714 @@ where
     S: OdeSystem<V>,
     V: VectorSpace + Clone,
     V::Field: Clone + PartialOrd + From<i32>,
+{
+    integrate_by(initial_state, t_end, n, |state, dt| {
+        solver.solve_step(f, state, dt)
+    })
+}
+
+/// Integrates as `integrate` a system whose evaluation can fail, e.g. outside of its domain.
+/// The integration stops after the step in which `f` fails, returning its first error while the states are discarded.
+/// Once failed, the remaining evaluations of the step return zero instead of evaluating `f`.
+pub fn try_integrate_fallible<X, V, E>(
+    solver: &X,
+    f: &dyn Fn(&TimeState<V>) -> Result<V, E>,
+    initial_state: TimeState<V>,
+    t_end: V::Field,
+    n: usize,
+) -> Result<Vec<TimeState<V>>, E>
+where
+    X: for<'a> OdeSolver<DynOdeSystem<'a, V>, V>,
+    V: VectorSpace,
+{
+    let error = RefCell::new(None);
+    let system = |s: &TimeState<V>| {
+        if error.borrow().is_some() {
+            return V::zero();
+        }
+        f(s).unwrap_or_else(|e| {
+            error.replace(Some(e));
+            V::zero()
+        })
+    };
+
+    solver.integrate_checked(
+        &(&system as DynOdeSystem<V>),
+        initial_state,
+        t_end,
+        n,
+        || error.take().map_or(Ok(()), Err),
+    )
+}
+
+/// The loop of `integrate` with `n` equidistant steps, each advancing a state by `solve_step`.
+fn integrate_by<V>(
+    initial_state: TimeState<V>,
+    t_end: V::Field,
+    n: usize,
+    mut solve_step: impl FnMut(&TimeState<V>, V::Field) -> TimeState<V>,
+) -> Vec<TimeState<V>>
+where
+    V: VectorSpace,
+    V::Field: Clone + PartialOrd + From<i32>,
+{
+    match try_integrate_by(initial_state, t_end, n, |state, dt| {
+        Ok::<_, Infallible>(solve_step(state, dt))
+    }) {
+        Ok(ys) => ys,
+        Err(never) => match never {},
+    }
+}
+
+/// As `integrate_by` for steps which can fail, stopping at the first error.
+fn try_integrate_by<V, E>(
+    initial_state: TimeState<V>,
+    t_end: V::Field,
+    n: usize,
+    mut solve_step: impl FnMut(&TimeState<V>, V::Field) -> Result<TimeState<V>, E>,
+) -> Result<Vec<TimeState<V>>, E>
+where
+    V: VectorSpace,
+    V::Field: Clone + PartialOrd + From<i32>,
+{
+    if t_end < initial_state.t || n < 1 {
+        return Ok(Vec::with_capacity(0));
+    }
+
+    let t0 = initial_state.t.clone();
+    let dt = (t_end - t0.clone()) / (n as i32).into();
+    let mut ys = Vec::with_capacity(n + 1);
+    ys.push(initial_state);
+
+    for step in 1..=n {
+        if let Some(state) = ys.last() {
+            let step = (step as i32).into();
+            let next_state = try_equidistant_step(&t0, &dt, &step, |dt| solve_step(state, dt))?;
+            ys.push(next_state);
+        }
+    }
+
+    Ok(ys)
+}
+
+/// Performs the `step`-th step of size `dt` by `solve_step`, reporting the time $t_0 + step \cdot dt$
+/// rather than accumulating $t + dt$, whose rounding errors drift over many steps.
+/// All integrations of first order systems with a fixed step size advance through this function,
+/// while `integrate_second_order` computes its times likewise.
+fn equidistant_step<V>(
+    t0: &V::Field,
+    dt: &V::Field,
+    step: usize,
+    solve_step: impl FnOnce(V::Field) -> TimeState<V>,
+) -> TimeState<V>
+where
+    V: VectorSpace,
+    V::Field: Clone + From<i32>,
+{
+    let step = (step as i32).into();
+    match try_equidistant_step(t0, dt, &step, |dt| Ok::<_, Infallible>(solve_step(dt))) {
+        Ok(next_state) => next_state,
+        Err(never) => match never {},
+    }
+}
+
+/// As `equidistant_step` for a step which can fail, where the index `step` is given in the field.
+fn try_equidistant_step<V, E>(
+    t0: &V::Field,
+    dt: &V::Field,
+    step: &V::Field,
+    solve_step: impl FnOnce(V::Field) -> Result<TimeState<V>, E>,
+) -> Result<TimeState<V>, E>
+where
+    V: VectorSpace,
+    V::Field: Clone,
+{
+    let mut next_state = solve_step(dt.clone())?;
+    next_state.t = t0.clone() + dt.clone() * step.clone();
+    Ok(next_state)
+}
+
+/// Integrates as `integrate`, additionally estimating the local error of each step by
+/// [step doubling](https://en.wikipedia.org/wiki/Richardson_extrapolation): the state after one step of size `dt`
+/// is compared with the state after two steps of size `dt / 2`, and the norm of their difference is reported.
+/// For a solver of order $p$ the error of the full step is about $2^p / (2^p - 1)$ times this difference.
+///
+/// The integration proceeds with the full steps, so the states coincide with those of `integrate`.
+/// The initial state is reported with an error of zero.
+pub fn integrate_with_error<X, S, V>(
+    solver: &X,
+    f: &S,
+    initial_state: TimeState<V>,
+    t_end: V::Field,
+    n: usize,
+) -> Vec<(TimeState<V>, V::Field)>
+where
+    X: OdeStepSolver<S, V>,
+    S: OdeSystem<V>,
+    V: VectorSpace + Norm + Clone,
+    V::Field: Clone + PartialOrd + From<i32>,
 {
     if t_end < initial_state.t || n < 1 {
         return Vec::with_capacity(0);
     }
 
-    let dt = (t_end.clone() - initial_state.t.clone()) / (n as i32).into();
+    let t0 = initial_state.t.clone();
+    let dt = (t_end - t0.clone()) / (n as i32).into();
+    let half_dt = dt.clone() / 2.into();
     let mut ys = Vec::with_capacity(n + 1);
+    ys.push((initial_state, 0.into()));
+
+    for step in 1..=n {
+        if let Some((state, _)) = ys.last() {
+            let next_state = equidistant_step(&t0, &dt, step, |dt| solver.solve_step(f, state, dt));
+            let half_state = solver.solve_step(f, state, half_dt.clone());
+            let refined_state = solver.solve_step(f, &half_state, half_dt.clone());
+            let error = (refined_state.y - next_state.y.clone()).norm();
+            ys.push((next_state, error));
+        }
+    }
+
+    ys
+}
+
+/// Integrates from the initial state through the given `times`, which need to be sorted increasingly and not
+/// precede the initial time. Between two consecutive times, `n_substeps` equidistant steps are taken so that
+/// each requested time is landed on exactly. Only the states at the requested `times` are returned.
+///
+/// NOTE: The integration stops at the first time which is not in order.
+pub fn integrate_at<X, S, V>(
+    solver: &X,
+    f: &S,
+    initial_state: TimeState<V>,
+    times: &[V::Field],
+    n_substeps: usize,
+) -> Vec<TimeState<V>>
+where
+    X: OdeStepSolver<S, V>,
+    S: OdeSystem<V>,
+    V: VectorSpace + Clone,
+    V::Field: Clone + PartialOrd + From<i32>,
+{
+    if n_substeps < 1 {
+        return Vec::with_capacity(0);
+    }
+
+    let mut ys = Vec::with_capacity(times.len());
+    let mut state = initial_state;
+
+    for t in times {
+        if *t < state.t {
+            break;
+        }
+
+        let t_previous = state.t.clone();
+        let dt = (t.clone() - t_previous.clone()) / (n_substeps as i32).into();
+        for step in 1..=n_substeps {
+            state = equidistant_step(&t_previous, &dt, step, |dt| {
+                solver.solve_step(f, &state, dt)
+            });
+        }
+        // land on the requested time exactly, which the last substep may miss by rounding
+        state.t = t.clone();
+        ys.push(state.clone());
+    }
+
+    ys
+}
+
+/// Integrates as `integrate` with `n` steps, but stores only every `keep_every`-th state, e.g. to save memory
+/// for long integrations with a fine resolution. The initial and the final state are always included.
+///
+/// Panics if `keep_every` is zero.
+pub fn integrate_decimated<X, S, V>(
+    solver: &X,
+    f: &S,
+    initial_state: TimeState<V>,
+    t_end: V::Field,
+    n: usize,
+    keep_every: usize,
+) -> Vec<TimeState<V>>
+where
+    X: OdeStepSolver<S, V>,
+    S: OdeSystem<V>,
+    V: VectorSpace + Clone,
+    V::Field: Clone + PartialOrd + From<i32>,
+{
+    assert!(keep_every > 0, "keep_every must be positive");
+    if t_end < initial_state.t || n < 1 {
+        return Vec::with_capacity(0);
+    }
+
+    let t0 = initial_state.t.clone();
+    let dt = (t_end - t0.clone()) / (n as i32).into();
+    let mut ys = Vec::with_capacity(n / keep_every + 2);
+    let mut state = initial_state;
+    ys.push(state.clone());
+
+    for step in 1..=n {
+        state = equidistant_step(&t0, &dt, step, |dt| solver.solve_step(f, &state, dt));
+        if step % keep_every == 0 || step == n {
+            ys.push(state.clone());
+        }
+    }
+
+    ys
+}
+
+/// Integrates as `integrate` with `n` steps, but stores only the states for which `keep` holds,
+/// e.g. those within a region of the phase space. This includes the initial state.
+pub fn integrate_filtered<X, S, V>(
+    solver: &X,
+    f: &S,
+    initial_state: TimeState<V>,
+    t_end: V::Field,
+    n: usize,
+    keep: impl Fn(&TimeState<V>) -> bool,
+) -> Vec<TimeState<V>>
+where
+    X: OdeStepSolver<S, V>,
+    S: OdeSystem<V>,
+    V: VectorSpace + Clone,
+    V::Field: Clone + PartialOrd + From<i32>,
+{
+    if t_end < initial_state.t || n < 1 {
+        return Vec::with_capacity(0);
+    }
+
+    let t0 = initial_state.t.clone();
+    let dt = (t_end - t0.clone()) / (n as i32).into();
+    let mut ys = Vec::new();
+    let mut state = initial_state;
+    if keep(&state) {
+        ys.push(state.clone());
+    }
+
+    for step in 1..=n {
+        state = equidistant_step(&t0, &dt, step, |dt| solver.solve_step(f, &state, dt));
+        if keep(&state) {
+            ys.push(state.clone());
+        }
+    }
+
+    ys
+}
+
+/// Integrates with the given sequence of step sizes `dts`, e.g. to resolve a known fast phase with small steps
+/// and a slow phase with large ones. One state is returned per step, preceded by the initial state.
+pub fn integrate_with_steps<X, S, V>(
+    solver: &X,
+    f: &S,
+    initial_state: TimeState<V>,
+    dts: &[V::Field],
+) -> Vec<TimeState<V>>
+where
+    X: OdeStepSolver<S, V>,
+    S: OdeSystem<V>,
+    V: VectorSpace + Clone,
+    V::Field: Clone,
+{
+    let mut ys = Vec::with_capacity(dts.len() + 1);
     ys.push(initial_state);
 
-    for _ in 0..n {
+    for dt in dts {
         if let Some(state) = ys.last() {
-            if state.t < t_end {
-                let next_state = solver.solve_step(f, state, dt.clone());
-                ys.push(next_state);
+            let next_state = solver.solve_step(f, state, dt.clone());
+            ys.push(next_state);
+        }
+    }
+
+    ys
+}
+
+/// Integrates with a fixed step size `dt` until the solution becomes stationary, that is until
+/// $\|y_{n+1} - y_n\| < tol$, or `max_steps` are taken.
+/// All states are returned, including the initial and the final one.
+pub fn integrate_to_steady_state<X, S, V>(
+    solver: &X,
+    f: &S,
+    initial_state: TimeState<V>,
+    max_steps: usize,
+    dt: V::Field,
+    tol: V::Field,
+) -> Vec<TimeState<V>>
+where
+    X: OdeStepSolver<S, V>,
+    S: OdeSystem<V>,
+    V: VectorSpace + Norm + Clone,
+    V::Field: Clone + PartialOrd + From<i32>,
+{
+    let t0 = initial_state.t.clone();
+    let mut ys = vec![initial_state];
+
+    for step in 1..=max_steps {
+        if let Some(state) = ys.last() {
+            let next_state = equidistant_step(&t0, &dt, step, |dt| solver.solve_step(f, state, dt));
+            let change = (next_state.y.clone() - state.y.clone()).norm();
+            ys.push(next_state);
+
+            if change < tol {
+                break;
             }
         }
     }
 
     ys
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        autonomous, from_ty, AdamsBashforthSolver, DynOdeSystem, EulerSolver, OdeSolver,
+        OdeStepSolver, Rk4Solver, TimeState, Trajectory,
+    };
+    use crate::test_support::{assert_slices_close, assert_time_series_close};
+    use nmbrs_algebra::Vector;
+
+    #[test]
+    fn integrate_to_steady_state() {
+        // y' = -y relaxes to 0
+        let f = |s: &TimeState<f64>| -s.y;
+        let initial_state = TimeState { t: 0.0, y: 1.0 };
+
+        let max_steps = 10_000;
+        let ys = Rk4Solver.integrate_to_steady_state(&f, initial_state, max_steps, 0.1, 1e-6);
+        assert!(ys.len() < max_steps / 10);
+
+        let final_state = ys.last().unwrap();
+        assert!(final_state.y.abs() < 1e-4);
+        assert!((final_state.y - (-final_state.t).exp()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn integrate_to_steady_state_max_steps() {
+        // y' = (1, -y) never becomes stationary in its first component
+        let f = |s: &TimeState<Vector<2, f64>>| {
+            let [_, y]: [f64; 2] = s.y.into();
+            Vector::new([1.0, -y])
+        };
+        let initial_state = TimeState {
+            t: 0.0,
+            y: Vector::new([0.0, 1.0]),
+        };
+
+        let ys = EulerSolver.integrate_to_steady_state(&f, initial_state, 100, 0.1, 1e-6);
+        assert_eq!(ys.len(), 101);
+    }
+
+    #[test]
+    fn integrate_with_error() {
+        let f = |s: &TimeState<f64>| s.y * s.t.sin();
+        let initial_state = TimeState { t: 0.0, y: -1.0 };
+
+        let max_error = |n: usize| {
+            let ys = Rk4Solver.integrate_with_error(&f, initial_state.clone(), 2.0, n);
+            assert_eq!(ys.len(), n + 1);
+            assert_eq!(ys[0].1, 0.0);
+            ys.iter().map(|(_, err)| *err).fold(0.0, f64::max)
+        };
+
+        // the local error of RK4 is of order 5, hence halving the step size reduces it by a factor of about 32
+        let coarse = max_error(20);
+        let fine = max_error(40);
+        assert!(fine > 0.0);
+        assert!(coarse / fine > 24.0 && coarse / fine < 40.0);
+
+        let states = Rk4Solver.integrate(&f, initial_state.clone(), 2.0, 20);
+        let ys = Rk4Solver.integrate_with_error(&f, initial_state, 2.0, 20);
+        for (s, (y, _)) in states.iter().zip(ys.iter()) {
+            assert_eq!(s.y, y.y);
+        }
+    }
+
+    #[test]
+    fn integrate_at() {
+        // y' = y t has the solution y = exp(t^2 / 2)
+        let f = |s: &TimeState<f64>| s.y * s.t;
+        let initial_state = TimeState { t: 0.0, y: 1.0 };
+        let times = [0.3, 0.35, 1.7];
+
+        let ys = Rk4Solver.integrate_at(&f, initial_state.clone(), &times, 100);
+        assert_eq!(ys.len(), times.len());
+        assert_slices_close(&ys.times(), &times, 0.0);
+        assert_time_series_close(&ys, |t| (t * t / 2.0).exp(), 1e-8);
+
+        // stops at times out of order
+        let ys = Rk4Solver.integrate_at(&f, initial_state, &[0.5, 0.2], 10);
+        assert_eq!(ys.len(), 1);
+    }
+
+    #[test]
+    fn integrate_with_steps() {
+        // y' = -y, resolving the fast initial decay with small steps
+        let f = |s: &TimeState<f64>| -s.y;
+        let initial_state = TimeState { t: 0.0, y: 1.0 };
+        let dts: Vec<f64> = [0.05; 10].into_iter().chain([0.25; 6]).collect();
+
+        let ys = Rk4Solver.integrate_with_steps(&f, initial_state.clone(), &dts);
+        assert_eq!(ys.len(), dts.len() + 1);
+        assert!(ys.windows(2).all(|w| w[0].t < w[1].t));
+
+        let final_state = ys.last().unwrap();
+        assert!((final_state.t - 2.0).abs() < 1e-12);
+        assert!((final_state.y - (-2.0_f64).exp()).abs() < 1e-5);
+
+        // coincides with `integrate` for uniform steps
+        let uniform = Rk4Solver.integrate_with_steps(&f, initial_state.clone(), &[0.1; 20]);
+        let zs = Rk4Solver.integrate(&f, initial_state.clone(), 2.0, 20);
+        assert_slices_close(&uniform.values(), &zs.values(), 1e-14);
+
+        assert_eq!(
+            Rk4Solver.integrate_with_steps(&f, initial_state, &[]).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn try_integrate_fallible() {
+        // y' = -1 / ln(y) is only defined for y > 0
+        let f = |s: &TimeState<f64>| {
+            if s.y <= 0.0 {
+                return Err(format!("log of non-positive {} at t = {}", s.y, s.t));
+            }
+            Ok(-1.0 / s.y.ln())
+        };
+        let initial_state = TimeState { t: 0.0, y: 3.0 };
+
+        let ys =
+            super::try_integrate_fallible(&Rk4Solver, &f, initial_state.clone(), 1.0, 10).unwrap();
+        assert_eq!(ys.len(), 11);
+        let expected = Rk4Solver.integrate(
+            &|s: &TimeState<f64>| -1.0 / s.y.ln(),
+            initial_state.clone(),
+            1.0,
+            10,
+        );
+        assert_eq!(ys.values(), expected.values());
+
+        // also for multistep solvers
+        let ys = super::try_integrate_fallible(
+            &AdamsBashforthSolver::TwoStep,
+            &f,
+            initial_state,
+            1.0,
+            10,
+        )
+        .unwrap();
+        assert_eq!(ys.len(), 11);
+
+        // y' = -1 reaches zero at t = 1
+        let g = |s: &TimeState<f64>| {
+            if s.y <= 0.0 {
+                return Err(s.t);
+            }
+            Ok(-1.0)
+        };
+        let initial_state = TimeState { t: 0.0, y: 1.0 };
+        let result = super::try_integrate_fallible(&EulerSolver, &g, initial_state.clone(), 2.0, 8);
+        assert_eq!(result.err(), Some(1.0));
+        let result = OdeSolver::<DynOdeSystem<f64>, f64>::try_integrate_fallible(
+            &Rk4Solver,
+            &g,
+            initial_state,
+            2.0,
+            8,
+        );
+        assert_eq!(result.err(), Some(1.0));
+    }
+
+    #[test]
+    fn integrate_checked_stops_at_first_error() {
+        let f = |s: &TimeState<f64>| -s.y;
+        let initial_state = TimeState { t: 0.0, y: 1.0 };
+
+        let mut n_checks = 0;
+        let result = Rk4Solver.integrate_checked(&f, initial_state.clone(), 1.0, 10, || {
+            n_checks += 1;
+            if n_checks == 3 {
+                return Err(n_checks);
+            }
+            Ok(())
+        });
+        assert_eq!(result.err(), Some(3));
+        assert_eq!(n_checks, 3);
+
+        let mut n_checks = 0;
+        let result =
+            AdamsBashforthSolver::FourStep.integrate_checked(&f, initial_state, 1.0, 10, || {
+                n_checks += 1;
+                if n_checks == 6 {
+                    return Err(n_checks);
+                }
+                Ok(())
+            });
+        assert_eq!(result.err(), Some(6));
+        assert_eq!(n_checks, 6);
+    }
+
+    #[test]
+    fn integrate_time_drift() {
+        let f = |_: &TimeState<f64>| 0.0;
+        let n = 100_000;
+        let states = EulerSolver.integrate(&f, TimeState { t: 0.0, y: 1.0 }, 10_000.0, n);
+        let dt = 0.1;
+
+        // the times as obtained by accumulating the step size
+        let mut accumulated = vec![0.0];
+        for _ in 0..n {
+            accumulated.push(accumulated.last().unwrap() + dt);
+        }
+
+        let drift = |times: &[f64]| {
+            times
+                .iter()
+                .enumerate()
+                .map(|(i, t)| (t - i as f64 * dt).abs())
+                .fold(0.0, f64::max)
+        };
+        assert!(drift(&accumulated) > 1e-9);
+        assert!(drift(&states.times()) < 1e-12);
+        assert_eq!(states.final_state().unwrap().t, 10_000.0);
+
+        // the other integrations with a fixed step size report the same times
+        let initial_state = TimeState { t: 0.0, y: 1.0 };
+        let adams =
+            AdamsBashforthSolver::FourStep.integrate(&f, initial_state.clone(), 10_000.0, n);
+        assert_eq!(adams.times(), states.times());
+        let with_error: Vec<f64> = EulerSolver
+            .integrate_with_error(&f, initial_state, 10_000.0, n)
+            .into_iter()
+            .map(|(s, _)| s.t)
+            .collect();
+        assert_eq!(with_error, states.times());
+    }
+
+    #[test]
+    fn integrate_decimated() {
+        let f = |s: &TimeState<f64>| s.y * s.t.sin();
+        let initial_state = TimeState { t: 0.0, y: -1.0 };
+        let n = 1_000;
+
+        let full = Rk4Solver.integrate(&f, initial_state.clone(), 5.0, n);
+        let decimated = Rk4Solver.integrate_decimated(&f, initial_state.clone(), 5.0, n, 30);
+        // the states 0, 30, ..., 990 and the final one
+        assert_eq!(decimated.len(), n / 30 + 2);
+        assert_eq!(decimated[1].y, full[30].y);
+
+        let (last, full_last) = (decimated.last().unwrap(), full.last().unwrap());
+        assert_eq!(last.t, full_last.t);
+        assert_eq!(last.y, full_last.y);
+
+        let decimated = Rk4Solver.integrate_decimated(&f, initial_state, 5.0, n, 100);
+        assert_eq!(decimated.len(), n / 100 + 1);
+    }
+
+    #[test]
+    fn integrate_filtered() {
+        // the harmonic oscillator x'' = -x, starting at its maximal displacement
+        let f = |s: &TimeState<Vector<2, f64>>| {
+            let [x, v]: [f64; 2] = s.y.into();
+            Vector::new([v, -x])
+        };
+        let initial_state = TimeState {
+            t: 0.0,
+            y: Vector::new([1.0, 0.0]),
+        };
+        let position = |s: &TimeState<Vector<2, f64>>| <[f64; 2]>::from(s.y)[0];
+        let n = 1_000;
+
+        let kept =
+            Rk4Solver.integrate_filtered(&f, initial_state.clone(), 10.0, n, |s| position(s) > 0.0);
+        assert!(!kept.is_empty());
+        assert!(kept.iter().all(|s| position(s) > 0.0));
+
+        // the same states as filtering the full trajectory afterwards
+        let full = Rk4Solver.integrate(&f, initial_state, 10.0, n);
+        let expected: Vec<f64> = full
+            .iter()
+            .filter(|s| position(s) > 0.0)
+            .map(|s| s.t)
+            .collect();
+        assert_eq!(kept.times(), expected);
+        // about half of the period
+        assert!(kept.len() > n / 3 && kept.len() < 2 * n / 3);
+    }
+
+    #[test]
+    fn integrate_detailed() {
+        let f = |s: &TimeState<f64>| -s.y;
+        let initial_state = TimeState { t: 1.0, y: 1.0 };
+        let (t_end, n) = (3.0, 8);
+
+        let output = Rk4Solver
+            .integrate_detailed(&f, initial_state.clone(), t_end, n)
+            .unwrap();
+        assert_eq!(output.dt, (t_end - 1.0) / n as f64);
+        assert_eq!(output.states.len(), n + 1);
+        assert_eq!(output.states.last().unwrap().t, t_end);
+
+        let output = AdamsBashforthSolver::TwoStep
+            .integrate_detailed(&f, initial_state.clone(), t_end, n)
+            .unwrap();
+        assert_eq!(output.dt, 0.25);
+        assert_eq!(output.states.len(), n + 1);
+
+        // no steps
+        assert!(Rk4Solver
+            .integrate_detailed(&f, initial_state.clone(), t_end, 0)
+            .is_none());
+        assert!(Rk4Solver
+            .integrate_detailed(&f, initial_state, 0.0, n)
+            .is_none());
+    }
+
+    #[test]
+    fn autonomous_closure() {
+        let f = autonomous(|y: &f64| -y);
+        let initial_state = TimeState { t: 0.0, y: 1.0 };
+
+        let ys = Rk4Solver.integrate(&f, initial_state, 2.0, 100);
+        assert_time_series_close(&ys, |t| (-t).exp(), 1e-9);
+    }
+
+    #[test]
+    fn from_ty_closure() {
+        let f = |s: &TimeState<f64>| s.y * s.t.sin();
+        let g = from_ty(|t: f64, y: &f64| y * t.sin());
+        let initial_state = TimeState { t: 0.0, y: -1.0 };
+
+        let ys = Rk4Solver.integrate(&f, initial_state.clone(), 10.0, 100);
+        let zs = Rk4Solver.integrate(&g, initial_state, 10.0, 100);
+        assert_eq!(ys.len(), zs.len());
+        for (y, z) in ys.iter().zip(zs.iter()) {
+            assert_eq!(y.t, z.t);
+            assert_eq!(y.y, z.y);
+        }
+    }
+
+    #[test]
+    fn trajectory() {
+        let f = |s: &TimeState<Vector<2, f64>>| {
+            let [x, y]: [f64; 2] = s.y.into();
+            Vector::new([y, -x])
+        };
+        let initial_state = TimeState {
+            t: 0.5,
+            y: Vector::new([1.0, 0.0]),
+        };
+
+        let states = Rk4Solver.integrate(&f, initial_state, 3.0, 50);
+        let ts = states.times();
+        let ys = states.values();
+        assert_eq!(ts.len(), states.len());
+        assert_eq!(ys.len(), states.len());
+        assert!(ts.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(states.unzip(), (ts, ys));
+
+        assert_eq!(states.initial_state().unwrap().t, 0.5);
+        assert!((states.final_state().unwrap().t - 3.0).abs() < 1e-12);
+
+        let empty: &[TimeState<f64>] = &[];
+        assert!(empty.final_state().is_none());
+        assert!(empty.times().is_empty());
+    }
+}
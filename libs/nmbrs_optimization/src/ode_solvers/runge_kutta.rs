@@ -1,4 +1,4 @@
-use super::{OdeStepSolver, OdeSystem, TimeState};
+use super::{OdeStepSolver, OdeSystem, OneStepMethod, TimeState};
 use nmbrs_algebra::{NumericField, VectorSpace};
 
 // https://en.wikipedia.org/wiki/Runge%E2%80%93Kutta_methods
@@ -50,6 +50,8 @@ impl Rk2Solver {
     }
 }
 
+impl OneStepMethod for Rk2Solver {}
+
 impl<S, V> OdeStepSolver<S, V> for Rk2Solver
 where
     S: OdeSystem<V>,
@@ -119,6 +121,8 @@ impl Rk4Solver {
     }
 }
 
+impl OneStepMethod for Rk4Solver {}
+
 impl<S, V> OdeStepSolver<S, V> for Rk4Solver
 where
     S: OdeSystem<V>,
@@ -185,8 +189,7 @@ mod tests {
             let dt: f64 = t_end / n as f64;
             let upper_bound = 5.0 * dt.powi(2);
 
-            for i in 0..n {
-                let s_i = &ys[i];
+            for s_i in ys.iter().take(n) {
                 let sol_i = sol(s_i.t);
                 let err_i = (sol_i - s_i.y).abs();
                 assert!(
@@ -218,8 +221,7 @@ mod tests {
             let dt: f64 = t_end / n as f64;
             let upper_bound = 5.0 * dt.powi(4);
 
-            for i in 0..n {
-                let s_i = &ys[i];
+            for s_i in ys.iter().take(n) {
                 let sol_i = sol(s_i.t);
                 let err_i = (sol_i - s_i.y).abs();
                 assert!(
@@ -0,0 +1,174 @@
+use crate::ode_solvers::{integrate_by, OdeSystem, TimeState};
+use std::f64::consts::PI;
+
+/// The [Euler-Maruyama method](https://en.wikipedia.org/wiki/Euler%E2%80%93Maruyama_method)
+/// for scalar [stochastic differential equations](https://en.wikipedia.org/wiki/Stochastic_differential_equation)
+/// $ dy = a(t, y) dt + b(t, y) dW $ with the drift $a$ and the diffusion $b$, advancing
+/// $ y_{n+1} = y_n + a(t_n, y_n) dt + b(t_n, y_n) \sqrt{dt} \xi_n $ with standard normal increments $\xi_n$.
+///
+/// The increments are drawn from a pseudo random number generator seeded by `seed`, hence a path is reproducible.
+///
+/// ```rust
+/// use nmbrs_optimization::ode_solvers::{EulerMaruyamaSolver, TimeState};
+///
+/// // the Ornstein-Uhlenbeck process dy = (1 - y) dt + 0.1 dW
+/// let drift = |s: &TimeState<f64>| 1.0 - s.y;
+/// let diffusion = |_: &TimeState<f64>| 0.1;
+/// let path = EulerMaruyamaSolver::new(42).integrate(&drift, &diffusion, TimeState { t: 0.0, y: 0.0 }, 1.0, 100);
+/// assert_eq!(path.len(), 101);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EulerMaruyamaSolver {
+    pub seed: u64,
+}
+
+impl EulerMaruyamaSolver {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Advances the state by `dt` for the given standard normal increment `xi`.
+    pub fn step<S, D>(
+        &self,
+        drift: &S,
+        diffusion: &D,
+        state: &TimeState<f64>,
+        dt: f64,
+        xi: f64,
+    ) -> TimeState<f64>
+    where
+        S: OdeSystem<f64>,
+        D: Fn(&TimeState<f64>) -> f64,
+    {
+        TimeState {
+            t: state.t + dt,
+            y: state.y + drift(state) * dt + diffusion(state) * dt.sqrt() * xi,
+        }
+    }
+
+    /// Integrates one path with `n` equidistant steps up to `t_end`, as `integrate` for ODEs.
+    pub fn integrate<S, D>(
+        &self,
+        drift: &S,
+        diffusion: &D,
+        initial_state: TimeState<f64>,
+        t_end: f64,
+        n: usize,
+    ) -> Vec<TimeState<f64>>
+    where
+        S: OdeSystem<f64>,
+        D: Fn(&TimeState<f64>) -> f64,
+    {
+        let mut normal = StandardNormal::new(self.seed);
+        integrate_by(initial_state, t_end, n, |state, dt| {
+            self.step(drift, diffusion, state, dt, normal.sample())
+        })
+    }
+}
+
+/// Standard normal samples by the [Box-Muller transform](https://en.wikipedia.org/wiki/Box%E2%80%93Muller_transform)
+/// of uniform samples of the [SplitMix64](https://prng.di.unimi.it/splitmix64.c) generator.
+struct StandardNormal {
+    state: u64,
+    /// The transform yields pairs of samples, of which the second is kept for the next call.
+    spare: Option<f64>,
+}
+
+impl StandardNormal {
+    fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            spare: None,
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A uniform sample in $(0, 1]$, excluding zero for the logarithm.
+    fn next_uniform(&mut self) -> f64 {
+        ((self.next_u64() >> 11) + 1) as f64 / (1_u64 << 53) as f64
+    }
+
+    fn sample(&mut self) -> f64 {
+        if let Some(spare) = self.spare.take() {
+            return spare;
+        }
+        let radius = (-2.0 * self.next_uniform().ln()).sqrt();
+        let angle = 2.0 * PI * self.next_uniform();
+        self.spare = Some(radius * angle.sin());
+        radius * angle.cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EulerMaruyamaSolver, StandardNormal};
+    use crate::ode_solvers::{TimeState, Trajectory};
+
+    #[test]
+    fn standard_normal() {
+        let mut normal = StandardNormal::new(7);
+        let n = 100_000;
+        let xs: Vec<f64> = (0..n).map(|_| normal.sample()).collect();
+        let mean = xs.iter().sum::<f64>() / n as f64;
+        let variance = xs.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n as f64;
+        assert!(mean.abs() < 0.02);
+        assert!((variance - 1.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn ornstein_uhlenbeck() {
+        // dy = theta (mu - y) dt + sigma dW with the stationary distribution N(mu, sigma^2 / (2 theta))
+        let (theta, mu, sigma) = (1.0, 2.0, 0.5);
+        let drift = |s: &TimeState<f64>| theta * (mu - s.y);
+        let diffusion = |_: &TimeState<f64>| sigma;
+        let initial_state = TimeState { t: 0.0, y: 0.0 };
+
+        let paths = 1_000;
+        let finals: Vec<f64> = (0..paths)
+            .map(|seed| {
+                let path = EulerMaruyamaSolver::new(seed).integrate(
+                    &drift,
+                    &diffusion,
+                    initial_state.clone(),
+                    10.0,
+                    1_000,
+                );
+                path.final_state().unwrap().y
+            })
+            .collect();
+
+        let mean = finals.iter().sum::<f64>() / paths as f64;
+        let variance =
+            finals.iter().map(|y| (y - mean) * (y - mean)).sum::<f64>() / (paths - 1) as f64;
+        assert!((mean - mu).abs() < 0.05);
+        assert!((variance - sigma * sigma / (2.0 * theta)).abs() < 0.025);
+    }
+
+    #[test]
+    fn reproducible() {
+        let drift = |s: &TimeState<f64>| -s.y;
+        let diffusion = |_: &TimeState<f64>| 1.0;
+        let integrate = |seed| {
+            EulerMaruyamaSolver::new(seed).integrate(
+                &drift,
+                &diffusion,
+                TimeState { t: 0.0, y: 1.0 },
+                1.0,
+                50,
+            )
+        };
+
+        assert_eq!(integrate(3).values(), integrate(3).values());
+        assert_ne!(integrate(3).values(), integrate(4).values());
+        assert!(EulerMaruyamaSolver::new(0)
+            .integrate(&drift, &diffusion, TimeState { t: 1.0, y: 1.0 }, 0.0, 10)
+            .is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+use nmbrs_algebra::VectorSpace;
+use std::fmt::Debug;
+
+/// A second order ODE $y'' = a(t, y, y')$, given by its acceleration `a`.
+pub trait SecondOrderSystem<V>: Fn(&SecondOrderState<V>) -> V
+where
+    V: VectorSpace,
+{
+}
+
+impl<F, V> SecondOrderSystem<V> for F
+where
+    F: Fn(&SecondOrderState<V>) -> V,
+    V: VectorSpace,
+{
+}
+
+/// The state of a second order system at time `t`, that is its position $y$ and its velocity $y'$.
+pub struct SecondOrderState<V>
+where
+    V: VectorSpace,
+{
+    pub t: <V as VectorSpace>::Field,
+    pub position: V,
+    pub velocity: V,
+}
+
+impl<V: VectorSpace> Clone for SecondOrderState<V>
+where
+    V: Clone,
+    V::Field: Clone,
+{
+    fn clone(&self) -> Self {
+        SecondOrderState::<V> {
+            t: self.t.clone(),
+            position: self.position.clone(),
+            velocity: self.velocity.clone(),
+        }
+    }
+}
+
+impl<V: VectorSpace> Debug for SecondOrderState<V>
+where
+    V: Debug,
+    V::Field: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{:?}: {:?}, {:?}]",
+            self.t, self.position, self.velocity
+        )
+    }
+}
+
+pub trait SecondOrderStepSolver<S, V>
+where
+    S: SecondOrderSystem<V>,
+    V: VectorSpace + Clone,
+    V::Field: Clone,
+{
+    fn solve_step(&self, f: &S, state: &SecondOrderState<V>, dt: V::Field) -> SecondOrderState<V>;
+}
+
+/// The [Velocity Verlet method](https://en.wikipedia.org/wiki/Verlet_integration#Velocity_Verlet)
+/// is a symplectic integrator of order 2 for second order ODEs.
+/// For conservative systems the energy error stays bounded, in contrast to e.g. the (non-symplectic) Runge Kutta methods.
+pub struct VerletSolver;
+
+impl VerletSolver {
+    pub fn step<S, V>(
+        &self,
+        f: &S,
+        state: &SecondOrderState<V>,
+        dt: V::Field,
+    ) -> SecondOrderState<V>
+    where
+        S: SecondOrderSystem<V>,
+        V: VectorSpace + Clone,
+        V::Field: Clone + From<i32>,
+    {
+        let half_dt = dt.clone() / V::Field::from(2);
+        let a = f(state);
+
+        // y_{n+1} = y_n + v_n * dt + a_n * dt^2 / 2
+        let t_step = state.t.clone() + dt.clone();
+        let position = state.position.clone()
+            + state.velocity.clone() * dt.clone()
+            + a.clone() * (dt.clone() * half_dt.clone());
+
+        // for velocity dependent accelerations, the velocity at t + dt is predicted by an Euler step
+        let a_step = f(&SecondOrderState {
+            t: t_step.clone(),
+            position: position.clone(),
+            velocity: state.velocity.clone() + a.clone() * dt,
+        });
+
+        // v_{n+1} = v_n + (a_n + a_{n+1}) * dt / 2
+        let velocity = state.velocity.clone() + (a + a_step) * half_dt;
+
+        SecondOrderState {
+            t: t_step,
+            position,
+            velocity,
+        }
+    }
+}
+
+impl<S, V> SecondOrderStepSolver<S, V> for VerletSolver
+where
+    S: SecondOrderSystem<V>,
+    V: VectorSpace + Clone,
+    V::Field: Clone + From<i32>,
+{
+    fn solve_step(&self, f: &S, state: &SecondOrderState<V>, dt: V::Field) -> SecondOrderState<V> {
+        self.step(f, state, dt)
+    }
+}
+
+/// Integrates the second order system `f` with `n` equidistant steps from the initial state up to `t_end`.
+pub fn integrate_second_order<X, S, V>(
+    solver: &X,
+    f: &S,
+    initial_state: SecondOrderState<V>,
+    t_end: V::Field,
+    n: usize,
+) -> Vec<SecondOrderState<V>>
+where
+    X: SecondOrderStepSolver<S, V>,
+    S: SecondOrderSystem<V>,
+    V: VectorSpace + Clone,
+    V::Field: Clone + PartialOrd + From<i32>,
+{
+    if t_end < initial_state.t || n < 1 {
+        return Vec::with_capacity(0);
+    }
+
+    let t0 = initial_state.t.clone();
+    let dt = (t_end - t0.clone()) / (n as i32).into();
+    let mut states = Vec::with_capacity(n + 1);
+    states.push(initial_state);
+
+    for step in 1..=n {
+        if let Some(state) = states.last() {
+            let mut next_state = solver.solve_step(f, state, dt.clone());
+            // as for the first order systems, the time is based on the index rather than accumulated
+            next_state.t = t0.clone() + dt.clone() * (step as i32).into();
+            states.push(next_state);
+        }
+    }
+
+    states
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SecondOrderState, VerletSolver};
+    use crate::ode_solvers::{OdeSolver, Rk4Solver, TimeState};
+    use nmbrs_algebra::Vector;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn verlet_harmonic_oscillator() {
+        // y'' = -y with solution y = cos(t)
+        let f = |s: &SecondOrderState<f64>| -s.position;
+        let initial_state = SecondOrderState {
+            t: 0.0,
+            position: 1.0,
+            velocity: 0.0,
+        };
+
+        let n = 1_000;
+        let states = super::integrate_second_order(&VerletSolver, &f, initial_state, 10.0, n);
+        assert_eq!(states.len(), n + 1);
+
+        // the times are not accumulated
+        assert_eq!(states[n].t, 10.0);
+
+        let dt: f64 = 10.0 / n as f64;
+        for s in states {
+            assert!((s.position - s.t.cos()).abs() <= 5.0 * dt.powi(2));
+            assert!((s.velocity + s.t.sin()).abs() <= 5.0 * dt.powi(2));
+        }
+    }
+
+    #[test]
+    fn verlet_energy_conservation() {
+        let energy =
+            |position: f64, velocity: f64| 0.5 * (position * position + velocity * velocity);
+
+        let n_periods = 1_000.0;
+        let t_end = n_periods * 2.0 * PI;
+        let n = 31_416;
+
+        // velocity Verlet on y'' = -y
+        let f = |s: &SecondOrderState<f64>| -s.position;
+        let initial_state = SecondOrderState {
+            t: 0.0,
+            position: 1.0,
+            velocity: 0.0,
+        };
+        let states = super::integrate_second_order(&VerletSolver, &f, initial_state, t_end, n);
+        let max_drift = states
+            .iter()
+            .map(|s| (energy(s.position, s.velocity) - 0.5).abs() / 0.5)
+            .fold(0.0, f64::max);
+        assert!(max_drift < 1e-2, "Verlet energy drift {}", max_drift);
+
+        // Runge Kutta of order 4 on the equivalent first order system (y, y')' = (y', -y)
+        let g = |s: &TimeState<Vector<2, f64>>| {
+            let [position, velocity]: [f64; 2] = s.y.into();
+            Vector::new([velocity, -position])
+        };
+        let initial_state = TimeState {
+            t: 0.0,
+            y: Vector::new([1.0, 0.0]),
+        };
+        let states = Rk4Solver.integrate(&g, initial_state, t_end, n);
+        let [position, velocity]: [f64; 2] = states.last().unwrap().y.into();
+        let final_drift = (energy(position, velocity) - 0.5).abs() / 0.5;
+        assert!(
+            final_drift > 1e-2,
+            "Runge Kutta energy drift {}",
+            final_drift
+        );
+    }
+}
@@ -1,49 +1,120 @@
-use crate::root_finder::RootFinderConfig;
+use crate::root_finder::{
+    counted, from_count, RootFinderConfig, RootFinderField, RootFindingResult,
+};
+use nmbrs_algebra::MidPoint;
+use std::cell::Cell;
 
 /// The [Bisection method](https://en.wikipedia.org/wiki/Bisection_method) is a root-finding method that applies
 /// to any continuous function for which one knows two values `a` and `b` with opposite signs for `f(a)` and `f(b)`.
-pub fn bisection<F>(f: F, a: f64, b: f64, config: Option<RootFinderConfig>) -> Option<f64>
+pub fn bisection<T, F>(f: F, a: T, b: T, config: Option<RootFinderConfig<T>>) -> Option<T>
 where
-    F: Fn(f64) -> f64,
+    T: RootFinderField + MidPoint,
+    F: Fn(T) -> T,
 {
+    bisection_core(f, a, b, config).0.map(|result| result.root)
+}
+
+/// The `bisection` method, additionally returning the successive midpoints of the bracketing intervals for diagnostics.
+/// If an endpoint is a root already, the trace consists only of that endpoint.
+pub fn bisection_trace<T, F>(
+    f: F,
+    a: T,
+    b: T,
+    config: Option<RootFinderConfig<T>>,
+) -> (Option<T>, Vec<T>)
+where
+    T: RootFinderField + MidPoint,
+    F: Fn(T) -> T,
+{
+    let (result, trace) = bisection_core(f, a, b, config);
+    (result.map(|result| result.root), trace)
+}
+
+/// The `bisection` method, returning the root along with the number of iterations and the half-width of the final
+/// bracketing interval as residual.
+///
+/// NOTE: In contrast to the other methods, which report $|f(x)|$, the residual of the bisection is a bound on the
+/// distance to the true root, as the returned midpoint and the root lie in the same interval.
+/// If an endpoint of the initial interval is a root already, the width of that interval is reported.
+pub fn bisection_detailed<T, F>(
+    f: F,
+    a: T,
+    b: T,
+    config: Option<RootFinderConfig<T>>,
+) -> Option<RootFindingResult<T>>
+where
+    T: RootFinderField + MidPoint,
+    F: Fn(T) -> T,
+{
+    bisection_core(f, a, b, config).0
+}
+
+fn bisection_core<T, F>(
+    f: F,
+    a: T,
+    b: T,
+    config: Option<RootFinderConfig<T>>,
+) -> (Option<RootFindingResult<T>>, Vec<T>)
+where
+    T: RootFinderField + MidPoint,
+    F: Fn(T) -> T,
+{
+    let config = config.unwrap_or_default();
+    let evaluations = Cell::new(0);
+    let f = counted(&f, &evaluations);
+
     let mut a = a;
     let mut b = b;
 
     if b < a {
         // std::mem::swap(&mut a, &mut b);
-        return None;
+        return (None, Vec::new());
     }
 
+    if !config.allows_evaluations(&evaluations, 2) {
+        return (None, Vec::new());
+    }
     let mut f_a = f(a);
     let f_b = f(b);
 
-    if f_a * f_b > 0.0 {
+    if f_a * f_b > T::zero() {
         // TODO: proper error handling
         // panic!("f(a) and f(b) must have opposite signs");
-        return None;
+        return (None, Vec::new());
     }
 
-    let config = config.unwrap_or_default();
-
     let tol = config.tolerance;
     let max_iterations = config.max_iterations;
     // .max_iterations
     // .unwrap_or(tol.log2().ceil() );
 
-    if f_a.abs() < tol {
-        return Some(a);
-    }
-    if f_b.abs() < tol {
-        return Some(b);
+    let width = b - a;
+    for (x, f_x) in [(a, f_a), (b, f_b)] {
+        if f_x.abs() < tol {
+            let result = RootFindingResult {
+                root: x,
+                iterations: 0,
+                residual: width,
+                function_evaluations: evaluations.get(),
+            };
+            return (Some(result), vec![x]);
+        }
     }
 
-    let mut mid: f64 = (a + b) / 2.0;
+    let mut mid = a.mid_point(b);
+    let mut trace = vec![mid];
+    if !config.allows_evaluations(&evaluations, 1) {
+        return (None, trace);
+    }
     let mut f_mid = f(mid);
     let mut iterations = 0;
 
     let mut delta = b - a;
     while delta > tol && f_mid.abs() > tol && iterations < max_iterations {
-        if f_a * f_mid < 0.0 {
+        if !config.allows_evaluations(&evaluations, 1) {
+            return (None, trace);
+        }
+        if f_a * f_mid < T::zero() {
             b = mid;
             // f_b = f_mid;
         } else {
@@ -51,15 +122,69 @@ where
             f_a = f_mid;
         }
         delta = b - a;
-        mid = (a + b) / 2.0;
+        mid = a.mid_point(b);
         f_mid = f(mid);
+        trace.push(mid);
         iterations += 1;
     }
-    Some(mid)
+    let result = RootFindingResult {
+        root: mid,
+        iterations,
+        residual: b - mid,
+        function_evaluations: evaluations.get(),
+    };
+    (Some(result), trace)
+}
+
+/// Scans the interval `[a, b]`, split into `subdivisions` sub-intervals of equal length, for a sign change of `f`
+/// and applies the `bisection` to the first sub-interval which brackets a root.
+/// This allows to find a root even if `f(a)` and `f(b)` share the same sign.
+///
+/// Returns `None` if no sub-interval brackets a root, e.g. if the subdivision is too coarse to separate two roots.
+pub fn bisection_scan<T, F>(
+    f: F,
+    a: T,
+    b: T,
+    subdivisions: usize,
+    config: Option<RootFinderConfig<T>>,
+) -> Option<T>
+where
+    T: RootFinderField + MidPoint,
+    F: Fn(T) -> T,
+{
+    if b < a || subdivisions == 0 {
+        return None;
+    }
+
+    let width = (b - a) / from_count(subdivisions);
+    let mut left = a;
+    let mut f_left = f(left);
+    // the index of the sub-interval, accumulated in the field
+    let mut index = T::zero();
+
+    for i in 1..=subdivisions {
+        index += T::one();
+        // evaluate the last point exactly at `b` to avoid rounding errors
+        let right = if i == subdivisions {
+            b
+        } else {
+            a + width * index
+        };
+        let f_right = f(right);
+
+        if f_left * f_right <= T::zero() {
+            return bisection(&f, left, right, config);
+        }
+
+        left = right;
+        f_left = f_right;
+    }
+    None
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::root_finder::RootFinderConfig;
     use approx::assert_abs_diff_eq;
     use std::f64::consts::SQRT_2;
 
@@ -97,6 +222,94 @@ mod tests {
         assert!(f(2.0) > 0.0);
         let root = super::bisection(f, 2.0, 2.0, None);
         assert!(root.is_none());
-        // TODO: provide version with randomized evaluations in the interval in order to find the root
+        // see `bisection_scan`
+    }
+
+    #[test]
+    fn bisection_scan() {
+        let f = |x: f64| x * x - 2.0;
+
+        // both endpoints share the same sign: f(-2) > 0 and f(2) > 0
+        assert!(super::bisection(f, -2.0, 2.0, None).is_none());
+        let root = super::bisection_scan(f, -2.0, 2.0, 4, None);
+        assert_abs_diff_eq!(root.unwrap(), -SQRT_2, epsilon = 1e-15);
+
+        // the subdivision is too coarse to separate the roots
+        assert!(super::bisection_scan(f, -2.0, 2.0, 1, None).is_none());
+        assert!(super::bisection_scan(f, -2.0, 2.0, 0, None).is_none());
+
+        // no root at all
+        assert!(super::bisection_scan(f, 3.0, 4.0, 100, None).is_none());
+
+        // the sub-intervals are stepped in the field, also for a fine subdivision in f32
+        let g = |x: f32| x - 0.75;
+        let root = super::bisection_scan(g, 0.0, 1.0, 1_000, None);
+        assert_abs_diff_eq!(root.unwrap(), 0.75, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn bisection_trace() {
+        let f = |x: f64| x * x - 2.0;
+
+        let (root, trace) = super::bisection_trace(f, 1.0, 2.0, None);
+        assert_eq!(root, trace.last().copied());
+        assert_eq!(trace[0], 1.5);
+
+        // the distance to the root is bounded by the half-width of the interval, which halves in each step
+        for (n, x) in trace.iter().enumerate() {
+            assert!((x - SQRT_2).abs() <= 0.5_f64.powi(n as i32 + 1));
+        }
+
+        let (root, trace) = super::bisection_trace(f, 3.0, 4.0, None);
+        assert!(root.is_none() && trace.is_empty());
+    }
+
+    #[test]
+    fn bisection_root_f32() {
+        let f = |x: f32| x * x - 2.0;
+
+        let root = super::bisection(f, 1.0_f32, 2.0, None);
+        assert_abs_diff_eq!(root.unwrap(), std::f32::consts::SQRT_2, epsilon = 1e-6);
+
+        let root = super::bisection_scan(f, -2.0_f32, 2.0, 4, None);
+        assert_abs_diff_eq!(root.unwrap(), -std::f32::consts::SQRT_2, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn bisection_detailed() {
+        let f = |x: f64| x * x - 2.0;
+
+        for config in [
+            None,
+            Some(RootFinderConfig::new().with_max_iterations(10)),
+            Some(RootFinderConfig::new().with_tolerance(1e-6)),
+        ] {
+            let result = super::bisection_detailed(f, 0.0, 3.0, config).unwrap();
+            assert!(result.residual > 0.0);
+            assert!((result.root - SQRT_2).abs() <= result.residual);
+        }
+
+        // the root is an endpoint
+        let result = super::bisection_detailed(|x: f64| x - 1.0, 1.0, 2.0, None).unwrap();
+        assert_eq!(result.root, 1.0);
+        assert_eq!(result.residual, 1.0);
+    }
+
+    #[test]
+    fn bisection_function_evaluation_budget() {
+        let f = |x: f64| x * x - 2.0;
+
+        let result = super::bisection_detailed(f, 0.0, 3.0, None).unwrap();
+        let config =
+            RootFinderConfig::new().with_max_function_evaluations(result.function_evaluations);
+        assert_eq!(
+            super::bisection_detailed(f, 0.0, 3.0, Some(config)),
+            Some(result)
+        );
+
+        // runs out of evaluations before the bracket is narrow enough
+        let config =
+            RootFinderConfig::new().with_max_function_evaluations(result.function_evaluations - 1);
+        assert!(super::bisection(f, 0.0, 3.0, Some(config)).is_none());
     }
 }
@@ -1,24 +1,101 @@
 mod bisection;
 mod newton;
+mod polynomial;
 mod secant;
 mod steffensen;
 
-pub use bisection::bisection;
-pub use newton::newton;
-pub use secant::secant;
-pub use steffensen::steffensen;
+pub use bisection::{bisection, bisection_detailed, bisection_scan, bisection_trace};
+pub use newton::{
+    newton, newton_complex, newton_damped, newton_detailed, newton_secant_hybrid, newton_trace,
+};
+pub use polynomial::polynomial_roots;
+pub use secant::{secant, secant_detailed, secant_trace};
+pub use steffensen::{steffensen, steffensen_detailed, steffensen_trace};
 
+use nmbrs_algebra::RealField;
+use std::cell::Cell;
+use std::fmt::{Display, LowerExp};
+use std::ops::AddAssign;
+
+/// The float fields the root finders operate on.
+pub trait RootFinderField: RealField + From<f32> + AddAssign + Copy {
+    /// The default tolerance of the `RootFinderConfig`, reflecting the precision of the field.
+    fn default_tolerance() -> Self;
+}
+
+impl RootFinderField for f32 {
+    fn default_tolerance() -> Self {
+        1e-6
+    }
+}
+
+impl RootFinderField for f64 {
+    fn default_tolerance() -> Self {
+        1e-15
+    }
+}
+
+/// A root found by one of the root finders, along with metrics of its convergence.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootFindingResult<T = f64> {
+    pub root: T,
+    /// The number of iterations performed.
+    pub iterations: usize,
+    /// An estimate of the error, see the respective method.
+    pub residual: T,
+    /// The number of evaluations of the function and, if required, its derivative.
+    pub function_evaluations: usize,
+}
+
+/// A one-liner such as `root=1.4142 (12 iters, residual=3.1e-16)`.
+/// The root is shown with the precision of the formatter, if any, and the residual in scientific notation.
+impl<T: Display + LowerExp> Display for RootFindingResult<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "root={:.precision$}", self.root)?,
+            None => write!(f, "root={}", self.root)?,
+        }
+        write!(
+            f,
+            " ({} iters, residual={:.1e})",
+            self.iterations, self.residual
+        )
+    }
+}
+
+/// The configuration of the root finders, generic over the float field `T` of the function.
 #[derive(Debug, Clone)]
-pub struct RootFinderConfig {
+pub struct RootFinderConfig<T = f64> {
     pub max_iterations: usize,
-    pub tolerance: f64,
+    pub tolerance: T,
+    /// The budget of evaluations of the function and its derivative, unlimited by default.
+    /// A solver checks the budget before each evaluation and returns `None` rather than exceeding it,
+    /// even if the iteration converged but its final residual cannot be evaluated.
+    pub max_function_evaluations: usize,
 }
 
-impl RootFinderConfig {
+impl<T: RootFinderField> RootFinderConfig<T> {
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// A config for `digits` correct decimal digits, that is the tolerance $10^{-digits}$.
+    /// The iterations suffice for bisection, which gains about $\log_2 10 \approx 3.3$ bits per digit,
+    /// on brackets up to a width of $2^{50}$.
+    ///
+    /// Panics if `digits` is zero or the tolerance underflows the field.
+    pub fn for_precision(digits: u32) -> Self {
+        assert!(digits > 0, "digits must be greater than 0");
+        let tolerance = T::from(10.0).powi(-(digits as i32));
+        assert!(
+            tolerance > T::zero(),
+            "digits exceed the range of the field"
+        );
+        Self::default()
+            .with_tolerance(tolerance)
+            .with_max_iterations(50 + 4 * digits as usize)
+    }
+
     pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
         if max_iterations == 0 {
             panic!("max_iterations must be greater than 0");
@@ -27,20 +104,55 @@ impl RootFinderConfig {
         self
     }
 
-    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
-        if tolerance <= 0.0 {
+    pub fn with_tolerance(mut self, tolerance: T) -> Self {
+        if tolerance <= T::zero() {
             panic!("tolerance must be greater than 0");
         }
         self.tolerance = tolerance;
         self
     }
+
+    pub fn with_max_function_evaluations(mut self, max_function_evaluations: usize) -> Self {
+        if max_function_evaluations == 0 {
+            panic!("max_function_evaluations must be greater than 0");
+        }
+        self.max_function_evaluations = max_function_evaluations;
+        self
+    }
+}
+
+impl<T> RootFinderConfig<T> {
+    /// Whether the budget allows for `required` further evaluations after `evaluations` were made.
+    fn allows_evaluations(&self, evaluations: &Cell<usize>, required: usize) -> bool {
+        self.max_function_evaluations
+            .saturating_sub(evaluations.get())
+            >= required
+    }
 }
 
-impl Default for RootFinderConfig {
+/// Wraps `f` to increment `evaluations` on each call.
+fn counted<'a, T, F>(f: &'a F, evaluations: &'a Cell<usize>) -> impl Fn(T) -> T + 'a
+where
+    F: Fn(T) -> T,
+{
+    move |x| {
+        evaluations.set(evaluations.get() + 1);
+        f(x)
+    }
+}
+
+/// The count `n` as an element of the field, summing ones since the field need not be `From<usize>`.
+/// Exact as long as the field represents the integers up to `n`, unlike a conversion via `f32`.
+pub(crate) fn from_count<T: RootFinderField>(n: usize) -> T {
+    (0..n).fold(T::zero(), |count, _| count + T::one())
+}
+
+impl<T: RootFinderField> Default for RootFinderConfig<T> {
     fn default() -> Self {
         Self {
             max_iterations: 100,
-            tolerance: 1e-15,
+            tolerance: T::default_tolerance(),
+            max_function_evaluations: usize::MAX,
         }
     }
 }
@@ -60,6 +172,9 @@ impl Default for RootFinderConfig {
 /// assert!( (root.unwrap() - 2.0_f64.sqrt()).abs() < 1e-15);
 /// // if you start with a guess that is too far away from the root or at a point where $df=0$, the algorithm might fail
 /// assert!(DerivativeSolver::newton_raphson(f, df, 0.0).try_find_root(None).is_none());
+/// // the hybrid falls back to secant steps where the derivative vanishes
+/// let root = DerivativeSolver::newton_secant_hybrid(f, df, 0.0).try_find_root(None);
+/// assert!( (root.unwrap() - 2.0_f64.sqrt()).abs() < 1e-15);
 ///
 /// // use the bisection algorithm which requires a bracketing interval
 /// use nmbrs_optimization::root_finder::BracketingSolver;
@@ -85,6 +200,15 @@ pub trait RootSolver {
     // TODO: return a Result instead of an Option, return also number of iterations and tolerance as metrics of convergence
 }
 
+impl<R: RootSolver + ?Sized> RootSolver for Box<R> {
+    fn try_find_root(&self, config: Option<RootFinderConfig>) -> Option<f64> {
+        (**self).try_find_root(config)
+    }
+}
+
+/// A type-erased function, so that solvers of different closures share a type and can be collected.
+pub type BoxedFn = Box<dyn Fn(f64) -> f64>;
+
 // TODO: rename
 pub enum BracketingSolver<F> {
     Bisection { f: F, a: f64, b: f64 },
@@ -109,6 +233,28 @@ where
     }
 }
 
+impl<F> BracketingSolver<F>
+where
+    F: Fn(f64) -> f64 + 'static,
+{
+    /// Erases the type of the function, see `BoxedFn`.
+    pub fn boxed(self) -> BracketingSolver<BoxedFn> {
+        match self {
+            Self::Bisection { f, a, b } => BracketingSolver::Bisection {
+                f: Box::new(f),
+                a,
+                b,
+            },
+            Self::Steffensen { f, x0 } => BracketingSolver::Steffensen { f: Box::new(f), x0 },
+            Self::Secant { f, x0, x1 } => BracketingSolver::Secant {
+                f: Box::new(f),
+                x0,
+                x1,
+            },
+        }
+    }
+}
+
 impl<F> RootSolver for BracketingSolver<F>
 where
     F: Fn(f64) -> f64,
@@ -124,6 +270,7 @@ where
 
 pub enum DerivativeSolver<F, DF> {
     NewtonRaphson { f: F, df: DF, x0: f64 },
+    NewtonSecantHybrid { f: F, df: DF, x0: f64 },
     // TODO: add combinations (Brent, etc)
 }
 
@@ -135,6 +282,7 @@ where
     fn try_find_root(&self, config: Option<RootFinderConfig>) -> Option<f64> {
         match self {
             Self::NewtonRaphson { f, df, x0 } => newton(f, df, *x0, config),
+            Self::NewtonSecantHybrid { f, df, x0 } => newton_secant_hybrid(f, df, *x0, config),
         }
     }
 }
@@ -147,4 +295,116 @@ where
     pub fn newton_raphson(f: F, df: DF, x0: f64) -> Self {
         Self::NewtonRaphson { f, df, x0 }
     }
+
+    pub fn newton_secant_hybrid(f: F, df: DF, x0: f64) -> Self {
+        Self::NewtonSecantHybrid { f, df, x0 }
+    }
+}
+
+impl<F, DF> DerivativeSolver<F, DF>
+where
+    F: Fn(f64) -> f64 + 'static,
+    DF: Fn(f64) -> f64 + 'static,
+{
+    /// Erases the types of the function and its derivative, see `BoxedFn`.
+    pub fn boxed(self) -> DerivativeSolver<BoxedFn, BoxedFn> {
+        match self {
+            Self::NewtonRaphson { f, df, x0 } => DerivativeSolver::NewtonRaphson {
+                f: Box::new(f),
+                df: Box::new(df),
+                x0,
+            },
+            Self::NewtonSecantHybrid { f, df, x0 } => DerivativeSolver::NewtonSecantHybrid {
+                f: Box::new(f),
+                df: Box::new(df),
+                x0,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BoxedFn, BracketingSolver, DerivativeSolver, RootFinderConfig, RootFindingResult,
+        RootSolver,
+    };
+
+    #[test]
+    fn heterogeneous_solvers() {
+        let f = |x: f64| x * x - 2.0;
+        let df = |x: f64| 2.0 * x;
+
+        let solvers: Vec<Box<dyn RootSolver>> = vec![
+            Box::new(BracketingSolver::bisection(f, 0.0, 2.0)),
+            Box::new(BracketingSolver::secant(f, 1.0, 2.0)),
+            Box::new(DerivativeSolver::newton_raphson(f, df, 1.0)),
+            Box::new(DerivativeSolver::newton_secant_hybrid(f, df, 1.0)),
+        ];
+        for solver in solvers.iter() {
+            let root = solver.try_find_root(None).unwrap();
+            assert!((root - 2.0_f64.sqrt()).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn boxed_functions() {
+        // closures of different types share a solver type once boxed
+        let shift = 3.0;
+        let solvers: Vec<BracketingSolver<BoxedFn>> = vec![
+            BracketingSolver::bisection(|x: f64| x - 1.0, 0.0, 2.0).boxed(),
+            BracketingSolver::bisection(move |x: f64| x - shift, 0.0, 4.0).boxed(),
+            BracketingSolver::Steffensen {
+                f: Box::new(|x: f64| x * x - 4.0),
+                x0: 3.0,
+            },
+        ];
+        let roots: Vec<f64> = solvers
+            .iter()
+            .map(|solver| solver.try_find_root(None).unwrap())
+            .collect();
+        for (root, expected) in roots.iter().zip([1.0, 3.0, 2.0]) {
+            assert!((root - expected).abs() < 1e-6);
+        }
+
+        let newton =
+            DerivativeSolver::newton_raphson(|x: f64| x.exp() - 1.0, f64::exp, 1.0).boxed();
+        assert!(newton.try_find_root(None).unwrap().abs() < 1e-6);
+    }
+
+    #[test]
+    fn for_precision() {
+        let config = RootFinderConfig::<f64>::for_precision(6);
+        assert_eq!(config.tolerance, 1e-6);
+
+        let f = |x: f64| x * x - 2.0;
+        let root = super::bisection(f, 0.0, 2.0, Some(config.clone())).unwrap();
+        assert!((root - 2.0_f64.sqrt()).abs() < 1e-6);
+        let root = super::secant(f, 1.0, 2.0, Some(config)).unwrap();
+        assert!((root - 2.0_f64.sqrt()).abs() < 1e-6);
+
+        assert_eq!(RootFinderConfig::<f32>::for_precision(3).tolerance, 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "digits exceed the range of the field")]
+    fn for_precision_underflow() {
+        RootFinderConfig::<f32>::for_precision(50);
+    }
+
+    #[test]
+    fn display_result() {
+        let result = super::newton_detailed(|x: f64| x * x - 2.0, |x| 2.0 * x, 1.0, None).unwrap();
+        let formatted = format!("{result:.4}");
+        assert!(formatted.starts_with("root=1.4142 ("));
+        assert!(formatted.contains(&format!("{} iters", result.iterations)));
+
+        let result = RootFindingResult {
+            root: 2.5_f32,
+            iterations: 3,
+            residual: 3.1e-7,
+            function_evaluations: 4,
+        };
+        assert_eq!(result.to_string(), "root=2.5 (3 iters, residual=3.1e-7)");
+    }
 }
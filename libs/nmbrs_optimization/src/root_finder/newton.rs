@@ -1,22 +1,89 @@
-use super::RootFinderConfig;
+use super::{counted, RootFinderConfig, RootFinderField, RootFindingResult};
+use nmbrs_algebra::Complex;
+use std::cell::Cell;
 
 /// The [Newton-Raphson method](https://en.wikipedia.org/wiki/Secant_method) for finding
 /// a root of a function `f`, given the derivative `df` of `f` and an initial guess `x0` for the root.
-pub fn newton<F, DF>(f: F, df: DF, x0: f64, config: Option<RootFinderConfig>) -> Option<f64>
+pub fn newton<T, F, DF>(f: F, df: DF, x0: T, config: Option<RootFinderConfig<T>>) -> Option<T>
 where
-    F: Fn(f64) -> f64,
-    DF: Fn(f64) -> f64,
+    T: RootFinderField,
+    F: Fn(T) -> T,
+    DF: Fn(T) -> T,
+{
+    newton_core(f, df, x0, config).0.map(|result| result.root)
+}
+
+/// The `newton` method, additionally returning the successive iterates $x_0, x_1, ...$ for diagnostics.
+pub fn newton_trace<T, F, DF>(
+    f: F,
+    df: DF,
+    x0: T,
+    config: Option<RootFinderConfig<T>>,
+) -> (Option<T>, Vec<T>)
+where
+    T: RootFinderField,
+    F: Fn(T) -> T,
+    DF: Fn(T) -> T,
+{
+    let (result, trace) = newton_core(f, df, x0, config);
+    (result.map(|result| result.root), trace)
+}
+
+/// The `newton` method, returning the root along with the number of iterations and the residual $|f(x)|$.
+pub fn newton_detailed<T, F, DF>(
+    f: F,
+    df: DF,
+    x0: T,
+    config: Option<RootFinderConfig<T>>,
+) -> Option<RootFindingResult<T>>
+where
+    T: RootFinderField,
+    F: Fn(T) -> T,
+    DF: Fn(T) -> T,
+{
+    newton_core(f, df, x0, config).0
+}
+
+/// The magnitude below which the derivative is considered to vanish: the tolerance, but at most $10^{-15}$.
+fn derivative_threshold<T: RootFinderField>(tol: T) -> T {
+    let threshold = T::from(1e-15);
+    if tol < threshold {
+        tol
+    } else {
+        threshold
+    }
+}
+
+fn newton_core<T, F, DF>(
+    f: F,
+    df: DF,
+    x0: T,
+    config: Option<RootFinderConfig<T>>,
+) -> (Option<RootFindingResult<T>>, Vec<T>)
+where
+    T: RootFinderField,
+    F: Fn(T) -> T,
+    DF: Fn(T) -> T,
 {
     let config = config.unwrap_or_default();
     let tol = config.tolerance;
     let max_iterations = config.max_iterations;
+    let derivative_threshold = derivative_threshold(tol);
+
+    let evaluations = Cell::new(0);
+    let f = counted(&f, &evaluations);
+    let df = counted(&df, &evaluations);
 
     let mut x = x0;
+    let mut trace = vec![x];
+    if !config.allows_evaluations(&evaluations, 2) {
+        return (None, trace);
+    }
     let mut df_x = df(x);
 
     // TODO: improve on thresholds, validations and error handling
-    if df_x.abs() < 1e-15_f64.min(tol) {
-        return None;
+    if df_x.abs() < derivative_threshold {
+        return (None, trace);
     }
 
     let mut f_x = f(x);
@@ -25,23 +92,228 @@ where
     let mut n_iterations = 0;
 
     while delta.abs() > tol && f_x.abs() > tol && n_iterations < max_iterations {
+        if !config.allows_evaluations(&evaluations, 2) {
+            return (None, trace);
+        }
         x += delta;
+        trace.push(x);
         f_x = f(x);
         df_x = df(x);
 
-        if df_x.abs() < 1e-15_f64.min(tol) {
-            return None;
+        if df_x.abs() < derivative_threshold {
+            return (None, trace);
         }
         delta = -f_x / df_x;
 
         n_iterations += 1;
     }
-    Some(x)
+    let result = RootFindingResult {
+        root: x,
+        iterations: n_iterations,
+        residual: f_x.abs(),
+        function_evaluations: evaluations.get(),
+    };
+    (Some(result), trace)
+}
+
+/// The `newton` method with a [backtracking line search](https://en.wikipedia.org/wiki/Backtracking_line_search):
+/// each Newton step $\Delta$ is scaled by the largest $\lambda \in \{1, 1/2, 1/4, ...\}$ for which
+/// $|f(x + \lambda \Delta)| < |f(x)|$, which widens the basin of convergence where full steps overshoot.
+pub fn newton_damped<T, F, DF>(
+    f: F,
+    df: DF,
+    x0: T,
+    config: Option<RootFinderConfig<T>>,
+) -> Option<T>
+where
+    T: RootFinderField,
+    F: Fn(T) -> T,
+    DF: Fn(T) -> T,
+{
+    const MAX_HALVINGS: usize = 50;
+
+    let config = config.unwrap_or_default();
+    let tol = config.tolerance;
+    let derivative_threshold = derivative_threshold(tol);
+    let half = T::from(0.5);
+
+    let evaluations = Cell::new(0);
+    let f = counted(&f, &evaluations);
+    let df = counted(&df, &evaluations);
+
+    let mut x = x0;
+    if !config.allows_evaluations(&evaluations, 1) {
+        return None;
+    }
+    let mut f_x = f(x);
+
+    for _ in 0..config.max_iterations {
+        if f_x.abs() < tol {
+            return Some(x);
+        }
+        // at least the derivative and the full step
+        if !config.allows_evaluations(&evaluations, 2) {
+            return None;
+        }
+
+        let df_x = df(x);
+        if df_x.abs() < derivative_threshold {
+            return None;
+        }
+        let delta = -f_x / df_x;
+
+        let mut lambda = T::one();
+        let mut x_next = x + delta;
+        let mut f_next = f(x_next);
+        let mut n_halvings = 0;
+        while f_next.abs() >= f_x.abs() {
+            n_halvings += 1;
+            if n_halvings > MAX_HALVINGS || !config.allows_evaluations(&evaluations, 1) {
+                // no decrease along the Newton direction, e.g. at a local extremum of |f|
+                return None;
+            }
+            lambda = lambda * half;
+            x_next = x + lambda * delta;
+            f_next = f(x_next);
+        }
+
+        x = x_next;
+        f_x = f_next;
+
+        if (lambda * delta).abs() < tol {
+            return Some(x);
+        }
+    }
+    None
+}
+
+/// A hybrid of the `newton` and the [Secant method](https://en.wikipedia.org/wiki/Secant_method):
+/// takes a Newton step where the derivative `df` is safely away from zero and falls back to a secant step
+/// through the last two iterates otherwise, rather than giving up on a vanishing derivative.
+/// If the derivative vanishes at the initial guess `x0`, a nearby point serves as the second iterate.
+pub fn newton_secant_hybrid<T, F, DF>(
+    f: F,
+    df: DF,
+    x0: T,
+    config: Option<RootFinderConfig<T>>,
+) -> Option<T>
+where
+    T: RootFinderField,
+    F: Fn(T) -> T,
+    DF: Fn(T) -> T,
+{
+    let config = config.unwrap_or_default();
+    let tol = config.tolerance;
+    let derivative_threshold = derivative_threshold(tol);
+
+    let evaluations = Cell::new(0);
+    let f = counted(&f, &evaluations);
+    let df = counted(&df, &evaluations);
+
+    let mut x = x0;
+    if !config.allows_evaluations(&evaluations, 1) {
+        return None;
+    }
+    let mut f_x = f(x);
+    // the previous iterate and its function value, used for the secant fallback
+    let mut previous: Option<(T, T)> = None;
+
+    for _ in 0..config.max_iterations {
+        if f_x.abs() < tol {
+            return Some(x);
+        }
+        // the derivative, possibly a nearby point for the secant, and the step
+        if !config.allows_evaluations(&evaluations, 3) {
+            return None;
+        }
+
+        let df_x = df(x);
+        let slope = if df_x.abs() > derivative_threshold {
+            df_x
+        } else {
+            let (x_prev, f_prev) = previous.unwrap_or_else(|| {
+                let x_near = x + T::from(1e-3) * (T::one() + x.abs());
+                (x_near, f(x_near))
+            });
+            (f_x - f_prev) / (x - x_prev)
+        };
+
+        if slope.abs() < derivative_threshold {
+            return None;
+        }
+
+        let delta = -f_x / slope;
+        previous = Some((x, f_x));
+        x += delta;
+        f_x = f(x);
+
+        if delta.abs() < tol {
+            return Some(x);
+        }
+    }
+    None
+}
+
+/// The `newton` method for a holomorphic function `f` of a complex variable with derivative `df`,
+/// starting from the complex initial guess `z0`. The stopping criteria apply to the modulus, i.e. it stops once
+/// $|f(z)|$ or the step $|\Delta z|$ fall below the tolerance.
+/// A real function may have complex roots only, which are found from a starting point off the real axis.
+///
+/// ```rust
+/// use nmbrs_algebra::Complex;
+/// use nmbrs_optimization::root_finder::newton_complex;
+///
+/// // z^3 = 1 has the roots 1 and (-1 ± i sqrt(3)) / 2
+/// let f = |z: Complex<f64>| z * z * z - Complex::from(1.0);
+/// let df = |z: Complex<f64>| (z * z).scale(3.0);
+/// let root = newton_complex(f, df, Complex::new(-1.0, 1.0), None).unwrap();
+/// assert!((root - Complex::new(-0.5, 3.0_f64.sqrt() / 2.0)).norm() < 1e-12);
+/// ```
+pub fn newton_complex<F, DF>(
+    f: F,
+    df: DF,
+    z0: Complex<f64>,
+    config: Option<RootFinderConfig<f64>>,
+) -> Option<Complex<f64>>
+where
+    F: Fn(Complex<f64>) -> Complex<f64>,
+    DF: Fn(Complex<f64>) -> Complex<f64>,
+{
+    let config = config.unwrap_or_default();
+    let tol = config.tolerance;
+    let derivative_threshold = derivative_threshold(tol);
+
+    let evaluations = Cell::new(0);
+    let f = counted(&f, &evaluations);
+    let df = counted(&df, &evaluations);
+
+    let mut z = z0;
+    let mut n_iterations = 0;
+
+    while n_iterations < config.max_iterations && config.allows_evaluations(&evaluations, 2) {
+        let f_z = f(z);
+        if f_z.norm() <= tol {
+            return Some(z);
+        }
+        let df_z = df(z);
+        if df_z.norm() < derivative_threshold {
+            return None;
+        }
+
+        let delta = -f_z / df_z;
+        z = z + delta;
+        if delta.norm() <= tol {
+            return Some(z);
+        }
+        n_iterations += 1;
+    }
+    None
 }
 
 #[cfg(test)]
 mod tests {
     use approx::assert_abs_diff_eq;
+    use nmbrs_algebra::Complex;
     use std::f64::consts::SQRT_2;
 
     #[test]
@@ -75,4 +347,124 @@ mod tests {
         let root = super::newton(f, df, 0.0, None);
         assert!(root.is_none());
     }
+
+    #[test]
+    fn newton_damped() {
+        let f = |x: f64| x.atan();
+        let df = |x: f64| 1.0 / (1.0 + x * x);
+
+        // full Newton steps overshoot with growing amplitude
+        let (_, trace) = super::newton_trace(f, df, 3.0, None);
+        assert!(trace[3].abs() > 1e3);
+
+        let root = super::newton_damped(f, df, 3.0, None);
+        assert_abs_diff_eq!(root.unwrap(), 0.0, epsilon = 1e-15);
+
+        let root = super::newton_damped(|x| x * x - 2.0, |x| 2.0 * x, 3.0, None);
+        assert_abs_diff_eq!(root.unwrap(), SQRT_2, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn newton_secant_hybrid() {
+        // the derivative vanishes at the inflection point x = 0
+        let f = |x: f64| x * x * x - 8.0;
+        let df = |x: f64| 3.0 * x * x;
+
+        assert!(super::newton(f, df, 0.0, None).is_none());
+
+        let root = super::newton_secant_hybrid(f, df, 0.0, None);
+        assert_abs_diff_eq!(root.unwrap(), 2.0, epsilon = 1e-14);
+
+        // coincides with newton for well-behaved derivatives
+        let root = super::newton_secant_hybrid(|x| x * x - 2.0, |x| 2.0 * x, 3.0, None);
+        assert_abs_diff_eq!(root.unwrap(), SQRT_2, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn newton_trace() {
+        let f = |x: f64| x * x - 2.0;
+        let df = |x: f64| 2.0 * x;
+
+        let max_iterations = crate::root_finder::RootFinderConfig::<f64>::default().max_iterations;
+        let (root, trace) = super::newton_trace(f, df, 3.0, None);
+        assert_eq!(root, trace.last().copied());
+        assert_eq!(trace[0], 3.0);
+        assert!(trace.len() < max_iterations);
+
+        // the residuals decrease strictly
+        for window in trace.windows(2) {
+            assert!(f(window[1]).abs() < f(window[0]).abs());
+        }
+    }
+
+    #[test]
+    fn newton_root_f32() {
+        let f = |x: f32| x * x - 2.0;
+        let df = |x: f32| 2.0 * x;
+
+        let root = super::newton(f, df, 3.0_f32, None);
+        assert_abs_diff_eq!(root.unwrap(), std::f32::consts::SQRT_2, epsilon = 1e-6);
+
+        let config = crate::root_finder::RootFinderConfig::<f32>::new().with_tolerance(1e-3);
+        let root = super::newton(f, df, 3.0_f32, Some(config));
+        assert_abs_diff_eq!(root.unwrap(), std::f32::consts::SQRT_2, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn newton_detailed() {
+        let f = |x: f64| x * x - 2.0;
+        let df = |x: f64| 2.0 * x;
+
+        let result = super::newton_detailed(f, df, 3.0, None).unwrap();
+        assert_abs_diff_eq!(result.root, SQRT_2, epsilon = 1e-15);
+        assert_eq!(result.residual, f(result.root).abs());
+        assert!(result.iterations > 0);
+        // the function and its derivative once per iteration and initially
+        assert_eq!(result.function_evaluations, 2 * (result.iterations + 1));
+    }
+
+    #[test]
+    fn newton_function_evaluation_budget() {
+        let evaluations = std::cell::Cell::new(0);
+        let f = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            x * x - 2.0
+        };
+        let df = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            2.0 * x
+        };
+
+        // the budget does not suffice for the initial function value and derivative
+        let config = crate::root_finder::RootFinderConfig::new().with_max_function_evaluations(1);
+        assert!(super::newton(f, df, 3.0, Some(config)).is_none());
+        assert_eq!(evaluations.get(), 0);
+
+        let result = super::newton_detailed(f, df, 3.0, None).unwrap();
+        evaluations.set(0);
+        let config = crate::root_finder::RootFinderConfig::new()
+            .with_max_function_evaluations(result.function_evaluations - 1);
+        assert!(super::newton(f, df, 3.0, Some(config)).is_none());
+        assert!(evaluations.get() < result.function_evaluations);
+    }
+
+    #[test]
+    fn newton_complex() {
+        // z^2 + 1 has the roots i and -i only
+        let f = |z: Complex<f64>| z * z + Complex::from(1.0);
+        let df = |z: Complex<f64>| z.scale(2.0);
+
+        let root = super::newton_complex(f, df, Complex::new(0.1, 0.9), None).unwrap();
+        assert!((root - Complex::i()).norm() < 1e-12);
+
+        let root = super::newton_complex(f, df, Complex::new(0.3, -2.0), None).unwrap();
+        assert!((root + Complex::i()).norm() < 1e-12);
+
+        // the derivative vanishes at the origin
+        assert!(super::newton_complex(f, df, Complex::from(0.0), None).is_none());
+
+        // the iterates of a real starting point remain real and never converge
+        let config = crate::root_finder::RootFinderConfig::new().with_max_iterations(50);
+        assert!(super::newton_complex(f, df, Complex::from(0.5), Some(config)).is_none());
+    }
 }
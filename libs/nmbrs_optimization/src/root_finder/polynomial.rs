@@ -0,0 +1,168 @@
+use super::{newton_complex, RootFinderConfig};
+use nmbrs_algebra::{AddIdentity, Complex};
+
+/// The angles (in radians) of the starting points on a circle around the origin, tried in turn.
+/// They are off the real axis, so that complex roots of a real polynomial can be reached.
+const RESTART_ANGLES: [f64; 5] = [0.7, 2.3, 3.9, 5.3, 1.5];
+
+/// Finds all (complex) roots of the real polynomial $a_0 x^n + a_1 x^{n-1} + ... + a_n$,
+/// given by its coefficients `coeffs` $= [a_0, ..., a_n]$ in descending order of the powers.
+///
+/// One root at a time is found by `newton_complex`, restarted from several points if necessary,
+/// and divided out of the polynomial by [synthetic division](https://en.wikipedia.org/wiki/Synthetic_division)
+/// ([deflation](https://en.wikipedia.org/wiki/Polynomial_root-finding#Deflation)) until the degree is one.
+/// Each root is finally polished by `newton_complex` on the original polynomial, to undo the errors of deflation.
+/// Roots of multiplicity $k$ are repeated $k$ times.
+///
+/// The roots are sorted by their real and then their imaginary parts.
+/// Returns fewer than $n$ roots if no further root is found, and none for a constant polynomial.
+///
+/// ```rust
+/// use nmbrs_optimization::root_finder::polynomial_roots;
+///
+/// // x^2 + 1 = (x - i)(x + i)
+/// let roots = polynomial_roots(&[1.0, 0.0, 1.0], None);
+/// assert_eq!(roots.len(), 2);
+/// assert!((roots[0].im + 1.0).abs() < 1e-12 && (roots[1].im - 1.0).abs() < 1e-12);
+/// ```
+pub fn polynomial_roots(
+    coeffs: &[f64],
+    config: Option<RootFinderConfig<f64>>,
+) -> Vec<Complex<f64>> {
+    // leading zeros do not contribute to the degree
+    let original: Vec<Complex<f64>> = coeffs
+        .iter()
+        .skip_while(|a| **a == 0.0)
+        .map(|a| Complex::from(*a))
+        .collect();
+    if original.len() < 2 {
+        return Vec::with_capacity(0);
+    }
+
+    let mut roots = Vec::with_capacity(original.len() - 1);
+    let mut deflated = original.clone();
+
+    while deflated.len() > 2 {
+        let Some(root) = find_root(&deflated, config.clone()) else {
+            break;
+        };
+        roots.push(root);
+        deflated = deflate(&deflated, root);
+    }
+    if deflated.len() == 2 {
+        roots.push(-deflated[1] / deflated[0]);
+    }
+
+    let mut roots: Vec<Complex<f64>> = roots
+        .into_iter()
+        .map(|root| {
+            newton_complex(
+                |z| evaluate(&original, z),
+                |z| evaluate_derivative(&original, z),
+                root,
+                config.clone(),
+            )
+            .unwrap_or(root)
+        })
+        .collect();
+    roots.sort_by(|z, w| z.re.total_cmp(&w.re).then(z.im.total_cmp(&w.im)));
+    roots
+}
+
+/// A root of the polynomial `p` by `newton_complex`, started from the points at `RESTART_ANGLES`
+/// on a circle within the bound $1 + \max_k |a_k / a_0|$ on the moduli of the roots.
+fn find_root(p: &[Complex<f64>], config: Option<RootFinderConfig<f64>>) -> Option<Complex<f64>> {
+    let leading = p[0].norm();
+    let bound = 1.0
+        + p.iter()
+            .skip(1)
+            .fold(0.0, |acc: f64, a| acc.max(a.norm() / leading));
+    let radius = 0.5 * bound;
+
+    RESTART_ANGLES.iter().find_map(|angle| {
+        let z0 = Complex::new(radius * angle.cos(), radius * angle.sin());
+        newton_complex(
+            |z| evaluate(p, z),
+            |z| evaluate_derivative(p, z),
+            z0,
+            config.clone(),
+        )
+    })
+}
+
+/// Evaluates the polynomial `p` at `z` by [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+fn evaluate(p: &[Complex<f64>], z: Complex<f64>) -> Complex<f64> {
+    p.iter().fold(Complex::zero(), |acc, a| acc * z + *a)
+}
+
+/// Evaluates the derivative of the polynomial `p` at `z`.
+fn evaluate_derivative(p: &[Complex<f64>], z: Complex<f64>) -> Complex<f64> {
+    let degree = p.len() - 1;
+    p.iter()
+        .take(degree)
+        .enumerate()
+        .fold(Complex::zero(), |acc, (k, a)| {
+            acc * z + a.scale((degree - k) as f64)
+        })
+}
+
+/// The quotient of the polynomial `p` divided by $(z - root)$, dropping the remainder.
+fn deflate(p: &[Complex<f64>], root: Complex<f64>) -> Vec<Complex<f64>> {
+    let mut quotient = Vec::with_capacity(p.len() - 1);
+    let mut carry = Complex::zero();
+    for a in p.iter().take(p.len() - 1) {
+        carry = carry * root + *a;
+        quotient.push(carry);
+    }
+    quotient
+}
+
+#[cfg(test)]
+mod tests {
+    use super::polynomial_roots;
+    use nmbrs_algebra::Complex;
+
+    #[test]
+    fn cubic_real_roots() {
+        // (x - 1)(x - 2)(x - 3)
+        let roots = polynomial_roots(&[1.0, -6.0, 11.0, -6.0], None);
+        assert_eq!(roots.len(), 3);
+        for (root, expected) in roots.iter().zip([1.0, 2.0, 3.0]) {
+            assert!((*root - Complex::from(expected)).norm() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn complex_roots() {
+        // (x^2 + 2x + 5)(x - 4) with the roots -1 ± 2i and 4
+        let roots = polynomial_roots(&[1.0, -2.0, -3.0, -20.0], None);
+        let expected = [
+            Complex::new(-1.0, -2.0),
+            Complex::new(-1.0, 2.0),
+            Complex::new(4.0, 0.0),
+        ];
+        assert_eq!(roots.len(), 3);
+        for (root, expected) in roots.iter().zip(expected) {
+            assert!((*root - expected).norm() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn degenerate_polynomials() {
+        assert!(polynomial_roots(&[], None).is_empty());
+        assert!(polynomial_roots(&[3.0], None).is_empty());
+        assert!(polynomial_roots(&[0.0, 0.0, 2.0], None).is_empty());
+
+        // leading zeros are ignored
+        let roots = polynomial_roots(&[0.0, 2.0, -1.0], None);
+        assert_eq!(roots, vec![Complex::new(0.5, 0.0)]);
+    }
+
+    #[test]
+    fn deterministic_order() {
+        let coeffs = [2.0, -3.0, -11.0, 6.0];
+        let roots = polynomial_roots(&coeffs, None);
+        assert_eq!(roots, polynomial_roots(&coeffs, None));
+        assert!(roots.windows(2).all(|w| w[0].re <= w[1].re));
+    }
+}
@@ -1,4 +1,5 @@
-use super::RootFinderConfig;
+use super::{counted, RootFinderConfig, RootFinderField, RootFindingResult};
+use std::cell::Cell;
 
 /*
 PYTHON
@@ -14,44 +15,100 @@ def secant_method(f, x0, x1, iterations):
 
 /// The [Secant method](https://en.wikipedia.org/wiki/Secant_method) for finding roots of a function `f`,
 /// provided two initial distinct guesses `x0` and `x1`  (ideally close to the root) for the root of `f`.
-pub fn secant<F>(f: F, x0: f64, x1: f64, config: Option<RootFinderConfig>) -> Option<f64>
+pub fn secant<T, F>(f: F, x0: T, x1: T, config: Option<RootFinderConfig<T>>) -> Option<T>
 where
-    F: Fn(f64) -> f64,
+    T: RootFinderField,
+    F: Fn(T) -> T,
+{
+    secant_core(f, x0, x1, config).0.map(|result| result.root)
+}
+
+/// The `secant` method, additionally returning the successive iterates $x_0, x_1, x_2, ...$ for diagnostics.
+pub fn secant_trace<T, F>(
+    f: F,
+    x0: T,
+    x1: T,
+    config: Option<RootFinderConfig<T>>,
+) -> (Option<T>, Vec<T>)
+where
+    T: RootFinderField,
+    F: Fn(T) -> T,
+{
+    let (result, trace) = secant_core(f, x0, x1, config);
+    (result.map(|result| result.root), trace)
+}
+
+/// The `secant` method, returning the root along with the number of iterations and the residual $|f(x)|$.
+pub fn secant_detailed<T, F>(
+    f: F,
+    x0: T,
+    x1: T,
+    config: Option<RootFinderConfig<T>>,
+) -> Option<RootFindingResult<T>>
+where
+    T: RootFinderField,
+    F: Fn(T) -> T,
+{
+    secant_core(f, x0, x1, config).0
+}
+
+fn secant_core<T, F>(
+    f: F,
+    x0: T,
+    x1: T,
+    config: Option<RootFinderConfig<T>>,
+) -> (Option<RootFindingResult<T>>, Vec<T>)
+where
+    T: RootFinderField,
+    F: Fn(T) -> T,
 {
     let config = config.unwrap_or_default();
     let tol = config.tolerance;
     let max_iterations = config.max_iterations;
 
+    let evaluations = Cell::new(0);
+    let f = counted(&f, &evaluations);
+
     let mut n_iterations = 0;
     let mut x0 = x0;
     let mut x1 = x1;
+    let mut trace = vec![x0, x1];
 
     if (x0 - x1).abs() < tol {
         panic!("initially guessed x0 and x1 are too close to each other");
-        return None;
     }
 
-    while n_iterations < max_iterations {
+    while n_iterations < max_iterations && config.allows_evaluations(&evaluations, 1) {
         let f_1 = f(x1);
         let x_diff = x1 - x0;
 
         if f_1.abs() < tol || x_diff.abs() < tol {
-            return Some(x1);
+            let result = RootFindingResult {
+                root: x1,
+                iterations: n_iterations,
+                residual: f_1.abs(),
+                function_evaluations: evaluations.get(),
+            };
+            return (Some(result), trace);
         }
 
+        if !config.allows_evaluations(&evaluations, 1) {
+            break;
+        }
         let f_diff = f_1 - f(x0);
 
         if f_diff.abs() < tol {
-            return None;
+            return (None, trace);
         }
 
         let x2 = x1 - f_1 * x_diff / f_diff;
 
         x0 = x1;
         x1 = x2;
+        trace.push(x1);
         n_iterations += 1;
     }
-    None
+    (None, trace)
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
-use super::RootFinderConfig;
+use super::{counted, RootFinderConfig, RootFinderField, RootFindingResult};
+use std::cell::Cell;
 
 /*
 PYTHON
@@ -36,40 +37,104 @@ def steff(f: Func, x: float) -> Iterator[float]:
 /// [Steffensen's method](https://en.wikipedia.org/wiki/Secant_method) for finding a root of a function `f`
 /// is similiar to Newton's method, but uses a first-order divided difference function as approximation for the
 /// derivative of `f`.
-pub fn steffensen<F>(f: F, x0: f64, config: Option<RootFinderConfig>) -> Option<f64>
+pub fn steffensen<T, F>(f: F, x0: T, config: Option<RootFinderConfig<T>>) -> Option<T>
 where
-    F: Fn(f64) -> f64,
+    T: RootFinderField,
+    F: Fn(T) -> T,
+{
+    steffensen_core(f, x0, config).0.map(|result| result.root)
+}
+
+/// The `steffensen` method, additionally returning the successive iterates $x_0, x_1, ...$ for diagnostics.
+pub fn steffensen_trace<T, F>(
+    f: F,
+    x0: T,
+    config: Option<RootFinderConfig<T>>,
+) -> (Option<T>, Vec<T>)
+where
+    T: RootFinderField,
+    F: Fn(T) -> T,
+{
+    let (result, trace) = steffensen_core(f, x0, config);
+    (result.map(|result| result.root), trace)
+}
+
+/// The `steffensen` method, returning the root along with the number of iterations and the residual $|f(x)|$.
+pub fn steffensen_detailed<T, F>(
+    f: F,
+    x0: T,
+    config: Option<RootFinderConfig<T>>,
+) -> Option<RootFindingResult<T>>
+where
+    T: RootFinderField,
+    F: Fn(T) -> T,
+{
+    steffensen_core(f, x0, config).0
+}
+
+fn steffensen_core<T, F>(
+    f: F,
+    x0: T,
+    config: Option<RootFinderConfig<T>>,
+) -> (Option<RootFindingResult<T>>, Vec<T>)
+where
+    T: RootFinderField,
+    F: Fn(T) -> T,
 {
     let config = config.unwrap_or_default();
     let tol = config.tolerance;
     let max_iterations = config.max_iterations;
 
+    let evaluations = Cell::new(0);
+    let f = counted(&f, &evaluations);
+
     let mut n_iterations = 0;
     let mut x = x0;
+    let mut trace = vec![x];
 
-    while n_iterations < max_iterations {
+    while n_iterations < max_iterations && config.allows_evaluations(&evaluations, 1) {
         let f_x = f(x);
 
         if f_x.abs() < tol {
-            return Some(x);
+            let result = RootFindingResult {
+                root: x,
+                iterations: n_iterations,
+                residual: f_x.abs(),
+                function_evaluations: evaluations.get(),
+            };
+            return (Some(result), trace);
         }
 
-        let df_x = f(x + f_x) / f_x - 1.0;
+        if !config.allows_evaluations(&evaluations, 1) {
+            break;
+        }
+        let df_x = f(x + f_x) / f_x - T::one();
 
         if df_x.abs() < tol {
-            return None;
+            return (None, trace);
         }
 
         let delta = -f_x / df_x;
         x += delta;
+        trace.push(x);
 
         if delta.abs() < tol {
-            return Some(x);
+            if !config.allows_evaluations(&evaluations, 1) {
+                break;
+            }
+            let residual = f(x).abs();
+            let result = RootFindingResult {
+                root: x,
+                iterations: n_iterations + 1,
+                residual,
+                function_evaluations: evaluations.get(),
+            };
+            return (Some(result), trace);
         }
 
         n_iterations += 1;
     }
-    None
+    (None, trace)
 }
 
 #[cfg(test)]
@@ -102,6 +167,28 @@ mod tests {
         assert_abs_diff_eq!(root.unwrap(), -SQRT_2, epsilon = 1e-15);
     }
 
+    #[test]
+    fn steffensen_function_evaluations() {
+        let f = |x: f64| x * x - 2.0;
+
+        // two evaluations per iteration, and one for the final residual
+        let result = super::steffensen_detailed(f, 3.0, None).unwrap();
+        assert!(result.iterations > 2);
+        assert_eq!(result.function_evaluations, 2 * result.iterations + 1);
+
+        // stops once the budget is exhausted
+        let config = crate::root_finder::RootFinderConfig::<f64>::new()
+            .with_max_function_evaluations(result.function_evaluations - 3);
+        assert!(super::steffensen(f, 3.0, Some(config)).is_none());
+
+        let config = crate::root_finder::RootFinderConfig::<f64>::new()
+            .with_max_function_evaluations(result.function_evaluations);
+        assert_eq!(
+            super::steffensen_detailed(f, 3.0, Some(config)),
+            Some(result)
+        );
+    }
+
     #[test]
     fn steffenson_no_root() {
         let f = |x: f64| x * x - 2.0;
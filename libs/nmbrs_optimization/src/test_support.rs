@@ -0,0 +1,86 @@
+//! Assertion helpers for comparing numerical results, available to downstream tests via the `test-util` feature.
+//!
+//! ```rust
+//! use nmbrs_optimization::ode_solvers::{OdeSolver, Rk4Solver, TimeState};
+//! use nmbrs_optimization::test_support::assert_time_series_close;
+//!
+//! let f = |s: &TimeState<f64>| -s.y;
+//! let states = Rk4Solver.integrate(&f, TimeState { t: 0.0, y: 1.0 }, 2.0, 100);
+//! assert_time_series_close(&states, |t| (-t).exp(), 1e-9);
+//! ```
+use crate::ode_solvers::TimeState;
+use approx::abs_diff_eq;
+
+/// Asserts that `actual` and `expected` are of equal length and agree componentwise up to the absolute tolerance `eps`.
+#[track_caller]
+pub fn assert_slices_close(actual: &[f64], expected: &[f64], eps: f64) {
+    assert_eq!(
+        actual.len(),
+        expected.len(),
+        "lengths differ: {} != {}",
+        actual.len(),
+        expected.len()
+    );
+    for (idx, (a, e)) in actual.iter().zip(expected.iter()).enumerate() {
+        assert!(
+            abs_diff_eq!(a, e, epsilon = eps),
+            "at index {idx}: {a} is not within {eps} of {e}"
+        );
+    }
+}
+
+/// Asserts that every state $(t_i, y_i)$ of `approx` agrees with the exact solution, i.e. $|y_i - y(t_i)| \leq$ `eps`.
+#[track_caller]
+pub fn assert_time_series_close(approx: &[TimeState<f64>], exact: impl Fn(f64) -> f64, eps: f64) {
+    for (idx, s) in approx.iter().enumerate() {
+        let expected = exact(s.t);
+        assert!(
+            abs_diff_eq!(s.y, expected, epsilon = eps),
+            "at state {idx} (t = {}): {} is not within {eps} of {expected}",
+            s.t,
+            s.y
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assert_slices_close, assert_time_series_close};
+    use crate::ode_solvers::TimeState;
+
+    #[test]
+    fn slices_close() {
+        assert_slices_close(&[1.0, 2.0], &[1.0 + 1e-10, 2.0 - 1e-10], 1e-9);
+        assert_slices_close(&[], &[], 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "at index 1")]
+    fn slices_not_close() {
+        assert_slices_close(&[1.0, 2.0], &[1.0, 2.1], 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "lengths differ")]
+    fn slices_of_different_length() {
+        assert_slices_close(&[1.0, 2.0], &[1.0], 1e-3);
+    }
+
+    #[test]
+    fn time_series_close() {
+        let states: Vec<TimeState<f64>> = (0..5)
+            .map(|i| TimeState {
+                t: i as f64,
+                y: 2.0 * i as f64 + 1e-12,
+            })
+            .collect();
+        assert_time_series_close(&states, |t| 2.0 * t, 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "at state 0 (t = 0)")]
+    fn time_series_not_close() {
+        let states = [TimeState { t: 0.0, y: 1.0 }];
+        assert_time_series_close(&states, |_| 0.0, 1e-3);
+    }
+}
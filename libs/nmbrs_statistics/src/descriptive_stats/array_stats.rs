@@ -1,5 +1,5 @@
 use crate::AsSlice;
-use nmbrs_algebra::{NumericField, NumericSemiGroup};
+use nmbrs_algebra::{Exp, Ln, NumericField, NumericSemiGroup, RealField, Sqrt};
 
 /*
 Array statistics provides routines optimized for single-dimensional arrays.
@@ -18,6 +18,20 @@ where
     Some(sum)
 }
 
+/// The sum of the samples as `sum`, cloning rather than copying them.
+/// This allows to sum types which are only `Clone`, such as arbitrary precision numbers.
+pub fn sum_ref<T>(xs: &[T]) -> Option<T>
+where
+    T: NumericSemiGroup + Clone,
+{
+    if xs.is_empty() {
+        return None;
+    }
+
+    let sum = xs.iter().fold(T::zero(), |acc, x| acc + x.clone());
+    Some(sum)
+}
+
 pub trait Sum<T> {
     fn sum(&self) -> Option<T>;
 }
@@ -32,16 +46,31 @@ pub trait Sum<T> {
 //     }
 // }
 
-impl<'a, T, S> Sum<T> for S
+impl<T, S> Sum<T> for S
 where
     S: AsSlice<T>,
-    T: 'a + NumericSemiGroup + Copy,
+    T: NumericSemiGroup + Clone,
 {
     fn sum(&self) -> Option<T> {
-        sum(self.as_slice())
+        sum_ref(self.as_slice())
     }
 }
 
+/// The sample size `n` as an element of the field, built by binary doubling to avoid any lossy cast.
+fn sample_size<T>(n: usize) -> T
+where
+    T: NumericField + Copy,
+{
+    (0..usize::BITS).rev().fold(T::zero(), |acc, bit| {
+        let doubled = acc + acc;
+        if (n >> bit) & 1 == 1 {
+            doubled + T::one()
+        } else {
+            doubled
+        }
+    })
+}
+
 /// The arithmetic mean or average of the provided samples.
 /// In statistics, the sample mean is a measure of the central tendency and estimates the expected value of the distribution.
 /// The mean is affected by outliers, so if you need a more robust estimate consider to use the Median instead.
@@ -52,48 +81,235 @@ where
 /// - [Wolfram MathWorld](http://mathworld.wolfram.com/SampleMean.html)
 pub fn mean<T>(xs: &[T]) -> Option<T>
 where
-    T: NumericField + From<i8> + Copy,
+    T: NumericField + Copy,
 {
-    let len = xs.len() as i8;
-    let sum: T = sum(xs)?;
-
-    Some(sum / len.into())
+    mean_iter(xs.iter().copied())
 }
 
 pub trait Mean<T> {
     fn mean(&self) -> Option<T>;
 }
 
-impl<'a, T, S> Mean<T> for S
+impl<T, S> Mean<T> for S
 where
     S: AsSlice<T>,
-    T: 'a + NumericField + From<i8> + Copy,
+    T: NumericField + Copy,
 {
     fn mean(&self) -> Option<T> {
         mean(self.as_slice())
     }
 }
 
+/// The sum of the items of an iterator, consuming it.
+/// In contrast to `Iterator::sum`, `None` is returned for an empty iterator.
+pub fn sum_iter<T, I>(iter: I) -> Option<T>
+where
+    I: Iterator<Item = T>,
+    T: NumericSemiGroup,
+{
+    let mut iter = iter;
+    let first = iter.next()?;
+    Some(iter.fold(first, |acc, x| acc + x))
+}
+
+/// The arithmetic mean of the items of an iterator, computed in a single pass with a running count.
+/// See also `mean`.
+pub fn mean_iter<T, I>(iter: I) -> Option<T>
+where
+    I: Iterator<Item = T>,
+    T: NumericField + Copy,
+{
+    let (sum, count) = iter.fold((T::zero(), T::zero()), |(sum, count), x| {
+        (sum + x, count + T::one())
+    });
+
+    if count == T::zero() {
+        return None;
+    }
+    Some(sum / count)
+}
+
+/// Provides the `mean` for iterators, without collecting the items first.
+pub trait MeanExt<T>: Iterator<Item = T> {
+    fn mean(self) -> Option<T>;
+}
+
+impl<T, I> MeanExt<T> for I
+where
+    I: Iterator<Item = T>,
+    T: NumericField + Copy,
+{
+    fn mean(self) -> Option<T> {
+        mean_iter(self)
+    }
+}
+
+/// Clamps the samples to the range `[lo, hi]`.
+/// The bounds are swapped if `lo > hi`.
+pub fn clamp_slice<T>(xs: &[T], lo: T, hi: T) -> Vec<T>
+where
+    T: PartialOrd + Copy,
+{
+    let (lo, hi) = if lo > hi { (hi, lo) } else { (lo, hi) };
+    xs.iter()
+        .map(|x| {
+            if *x < lo {
+                lo
+            } else if *x > hi {
+                hi
+            } else {
+                *x
+            }
+        })
+        .collect()
+}
+
+/// The `mean` of the samples clamped to the range `[lo, hi]`, limiting the influence of outliers
+/// in bounded measurements. The bounds are swapped if `lo > hi`.
+pub fn clamped_mean<T>(xs: &[T], lo: T, hi: T) -> Option<T>
+where
+    T: NumericField + PartialOrd + Copy,
+{
+    mean(&clamp_slice(xs, lo, hi))
+}
+
+/// Maps the samples linearly from their range `[min, max]` to `[lo, hi]`, e.g. to rescale features into `[0, 1]`.
+/// See `normalize_range_in_place` for the in-place variant.
+///
+/// Returns `None` for empty or constant samples, and for incomparable values.
+pub fn normalize_range<T>(xs: &[T], lo: T, hi: T) -> Option<Vec<T>>
+where
+    T: NumericField + PartialOrd + Copy,
+{
+    let mut ys = xs.to_vec();
+    normalize_range_in_place(&mut ys, lo, hi)?;
+    Some(ys)
+}
+
+/// Maps the samples linearly from their range `[min, max]` to `[lo, hi]` in place, see `normalize_range`.
+/// The samples are left unchanged if `None` is returned.
+pub fn normalize_range_in_place<T>(xs: &mut [T], lo: T, hi: T) -> Option<()>
+where
+    T: NumericField + PartialOrd + Copy,
+{
+    let (first, rest) = xs.split_first()?;
+    let (mut min, mut max) = (*first, *first);
+    for x in rest {
+        if *x < min {
+            min = *x;
+        } else if *x > max {
+            max = *x;
+        } else if x.partial_cmp(&min).is_none() {
+            return None;
+        }
+    }
+    let range = max - min;
+    if range == T::zero() {
+        return None;
+    }
+
+    for x in xs.iter_mut() {
+        // the extremes are mapped exactly onto the bounds, regardless of rounding
+        *x = if *x == min {
+            lo
+        } else if *x == max {
+            hi
+        } else {
+            (lo * (max - *x) + hi * (*x - min)) / range
+        };
+    }
+    Some(())
+}
+
+/// The samples without the `NaN` entries, e.g. the gaps of a sensor series.
+fn without_nan(xs: &[f64]) -> Vec<f64> {
+    xs.iter().copied().filter(|x| !x.is_nan()).collect()
+}
+
+/// The `mean` of the samples ignoring `NaN` entries, which would otherwise propagate to the result.
+/// Returns `None` if all samples are `NaN`.
+pub fn nan_mean(xs: &[f64]) -> Option<f64> {
+    mean(&without_nan(xs))
+}
+
+/// The `variance` of the samples ignoring `NaN` entries, see `nan_mean`.
+pub fn nan_variance(xs: &[f64], ty: Option<VarianceBias>) -> Option<f64> {
+    variance(&without_nan(xs), ty)
+}
+
 /// The (biased) [sample variance](https://en.wikipedia.org/wiki/Variance#Sample_variance).
 ///
+/// Computed from the `sum_of_squares`, which avoids the catastrophic cancellation for samples with a large mean and a small spread.
+/// Returns `None` for empty samples, and for a single sample in case of the `VarianceBias::Sample`.
+///
 /// NOTE: The variance is covered by the `Covariance` but provided as a more performant function.
 /// Sources:
 /// * [MathDotNet](https://numerics.mathdotnet.com/DescriptiveStatistics)
-/// * [Wikipedia](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Two-pass_algorithm)
 pub fn variance<T>(xs: &[T], ty: Option<VarianceBias>) -> Option<T>
 where
-    T: NumericField + From<i8> + Copy,
+    T: NumericField + Copy,
 {
-    let len = xs.len() as i8;
-    let mean = mean(xs)?;
+    let m2 = sum_of_squares(xs)?;
+    let scale = ty.unwrap_or_default().scale(sample_size::<T>(xs.len()));
+    if scale == T::zero() {
+        return None;
+    }
+    Some(m2 / scale)
+}
 
-    let mse = xs.iter().fold(T::zero(), |err, x| {
-        let x_err = -*x + mean;
-        err + x_err * x_err
-    });
+/// The sum of squared deviations from the mean $ \Sigma (x_i - \bar x)^2 $, the building block of the `variance`.
+///
+/// Computed by [Welford's algorithm](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm),
+/// which updates a running mean and the squared deviations from it with each sample.
+/// As the running mean truncates for integer samples, the deviations from it are summed up as well
+/// and corrected for at the end, dividing only once. For floats these corrections vanish up to rounding.
+/// Returns `None` for empty samples.
+pub fn sum_of_squares<T>(xs: &[T]) -> Option<T>
+where
+    T: NumericField + Copy,
+{
+    sum_of_products(xs, xs)
+}
+
+/// The sum of products of the deviations from the respective means $ \Sigma (x_i - \bar x)(y_i - \bar y) $,
+/// the building block of the `covariance`.
+///
+/// Computed by Welford's algorithm, see `sum_of_squares`.
+/// Returns `None` if the lengths do not match or the samples are empty.
+pub fn sum_of_products<T>(xs: &[T], ys: &[T]) -> Option<T>
+where
+    T: NumericField + Copy,
+{
+    if xs.len() != ys.len() {
+        return None;
+    }
+
+    let mut n = T::zero();
+    let (mut x_mean, mut y_mean) = (T::zero(), T::zero());
+    // the sums of the deviations from the running means, which vanish unless the division truncates
+    let (mut x_deviations, mut y_deviations) = (T::zero(), T::zero());
+    let mut products = T::zero();
+
+    for (x, y) in xs.iter().zip(ys.iter()) {
+        n = n + T::one();
+        let (dx, dy) = (*x - x_mean, *y - y_mean);
+        let (x_step, y_step) = (dx / n, dy / n);
+        x_mean = x_mean + x_step;
+        y_mean = y_mean + y_step;
 
-    let scale = ty.unwrap_or_default().scale(len);
-    Some(mse / scale.into())
+        // the remainders of the divisions
+        let (x_remainder, y_remainder) = (x_step * n - dx, y_step * n - dy);
+        products = products + dx * (*y - y_mean) + x_step * y_remainder
+            - y_step * x_deviations
+            - x_step * y_deviations;
+        x_deviations = x_deviations - x_remainder;
+        y_deviations = y_deviations - y_remainder;
+    }
+
+    if n == T::zero() {
+        return None;
+    }
+    Some(products - x_deviations * y_deviations / n)
 }
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
@@ -106,36 +322,214 @@ pub enum VarianceBias {
 }
 
 impl VarianceBias {
-    fn scale(&self, n: i8) -> i8 {
+    fn scale<T: NumericField>(&self, n: T) -> T {
         match self {
             VarianceBias::Population => n,
-            VarianceBias::Sample => n - 1,
+            VarianceBias::Sample => n - T::one(),
         }
     }
 }
 
 pub trait Variance<T> {
-    /// TODO: add docs as above
-    fn sample_variance(&self) -> Option<T>;
-    fn population_variance(&self) -> Option<T>;
+    /// The `variance` with the given `bias`, e.g. when the estimator is chosen at runtime.
+    fn variance_with(&self, bias: VarianceBias) -> Option<T>;
+    /// The unbiased `variance`, dividing by $n - 1$. Returns `None` for less than two samples.
+    fn sample_variance(&self) -> Option<T> {
+        self.variance_with(VarianceBias::Sample)
+    }
+    /// The biased `variance`, dividing by $n$. Returns `None` for empty samples.
+    fn population_variance(&self) -> Option<T> {
+        self.variance_with(VarianceBias::Population)
+    }
 }
 
-impl<'a, T, S> Variance<T> for S
+impl<T, S> Variance<T> for S
 where
     S: AsSlice<T>,
-    T: 'a + NumericField + From<i8> + Copy,
+    T: NumericField + Copy,
 {
-    fn population_variance(&self) -> Option<T> {
-        variance(self.as_slice(), Some(VarianceBias::Population))
+    fn variance_with(&self, bias: VarianceBias) -> Option<T> {
+        variance(self.as_slice(), Some(bias))
     }
-    fn sample_variance(&self) -> Option<T> {
-        variance(self.as_slice(), Some(VarianceBias::Sample))
+}
+
+/// The [pooled variance](https://en.wikipedia.org/wiki/Pooled_variance) of several groups,
+/// i.e. the average of the groups' sample variances weighted by their degrees of freedom $n_i - 1$.
+///
+/// Groups with less than two samples do not contribute any degrees of freedom.
+/// Returns `None` if there are no degrees of freedom at all.
+pub fn pooled_variance<T>(groups: &[&[T]]) -> Option<T>
+where
+    T: NumericField + Copy,
+{
+    let mut weighted_sum = T::zero();
+    let mut degrees_of_freedom = T::zero();
+
+    for group in groups.iter().filter(|group| group.len() > 1) {
+        let df = sample_size::<T>(group.len()) - T::one();
+        weighted_sum = weighted_sum + variance(group, Some(VarianceBias::Sample))? * df;
+        degrees_of_freedom = degrees_of_freedom + df;
+    }
+
+    if degrees_of_freedom == T::zero() {
+        return None;
+    }
+    Some(weighted_sum / degrees_of_freedom)
+}
+
+/// The (biased) [weighted variance](https://en.wikipedia.org/wiki/Weighted_arithmetic_mean#Weighted_sample_variance)
+/// $ \Sigma w_i (x_i - \mu_w)^2 / \Sigma w_i $ around the weighted mean $\mu_w$.
+///
+/// Returns `None` if the lengths do not match, the samples are empty or the weights sum up to zero.
+pub fn weighted_variance<T>(xs: &[T], weights: &[T]) -> Option<T>
+where
+    T: NumericField + Copy,
+{
+    if xs.is_empty() || xs.len() != weights.len() {
+        return None;
+    }
+
+    let weight_sum = sum(weights)?;
+    if weight_sum == T::zero() {
+        return None;
+    }
+
+    let weighted_mean = dot(xs, weights)? / weight_sum;
+    let mse = xs
+        .iter()
+        .zip(weights.iter())
+        .fold(T::zero(), |err, (x, w)| {
+            let x_err = *x - weighted_mean;
+            err + *w * x_err * x_err
+        });
+
+    Some(mse / weight_sum)
+}
+
+/// The [standard deviation](https://en.wikipedia.org/wiki/Standard_deviation), i.e. the square root of the `variance`.
+pub fn standard_deviation<T>(xs: &[T], ty: Option<VarianceBias>) -> Option<T>
+where
+    T: NumericField + Sqrt + Copy,
+{
+    variance(xs, ty).map(T::sqrt)
+}
+
+pub trait StandardDeviation<T> {
+    fn sample_standard_deviation(&self) -> Option<T>;
+    fn population_standard_deviation(&self) -> Option<T>;
+}
+
+impl<T, S> StandardDeviation<T> for S
+where
+    S: AsSlice<T>,
+    T: NumericField + Sqrt + Copy,
+{
+    fn sample_standard_deviation(&self) -> Option<T> {
+        standard_deviation(self.as_slice(), Some(VarianceBias::Sample))
+    }
+    fn population_standard_deviation(&self) -> Option<T> {
+        standard_deviation(self.as_slice(), Some(VarianceBias::Population))
+    }
+}
+
+/// The [coefficient of variation](https://en.wikipedia.org/wiki/Coefficient_of_variation),
+/// that is the sample standard deviation relative to the mean.
+/// Returns `None` if the mean is zero.
+pub fn coefficient_of_variation<T>(xs: &[T]) -> Option<T>
+where
+    T: NumericField + Sqrt + Copy,
+{
+    let mean = mean(xs)?;
+    if mean == T::zero() {
+        return None;
+    }
+    let std = standard_deviation(xs, Some(VarianceBias::Sample))?;
+    Some(std / mean)
+}
+
+pub trait CoefficientOfVariation<T> {
+    fn coefficient_of_variation(&self) -> Option<T>;
+}
+
+impl<T, S> CoefficientOfVariation<T> for S
+where
+    S: AsSlice<T>,
+    T: NumericField + Sqrt + Copy,
+{
+    fn coefficient_of_variation(&self) -> Option<T> {
+        coefficient_of_variation(self.as_slice())
+    }
+}
+
+/// The logarithms of the samples, or `None` if a sample is not positive.
+fn logarithms<T>(xs: &[T]) -> Option<Vec<T>>
+where
+    T: RealField + Ln + Copy,
+{
+    xs.iter()
+        .map(|x| if *x > T::zero() { Some(x.ln()) } else { None })
+        .collect()
+}
+
+/// The [geometric mean](https://en.wikipedia.org/wiki/Geometric_mean) $ \exp(\frac{1}{n} \Sigma \ln x_i) $.
+/// Returns `None` if a sample is not positive.
+pub fn geometric_mean<T>(xs: &[T]) -> Option<T>
+where
+    T: RealField + Ln + Exp + Copy,
+{
+    mean(&logarithms(xs)?).map(T::exp)
+}
+
+/// The [geometric standard deviation](https://en.wikipedia.org/wiki/Geometric_standard_deviation)
+/// $ \exp(\sigma(\ln x_i)) $, that is the multiplicative spread around the `geometric_mean`,
+/// based on the sample standard deviation of the logarithms.
+/// It is the natural measure of dispersion for log-normally distributed data.
+/// Returns `None` if a sample is not positive.
+pub fn geometric_std<T>(xs: &[T]) -> Option<T>
+where
+    T: RealField + Ln + Exp + Copy,
+{
+    standard_deviation(&logarithms(xs)?, Some(VarianceBias::Sample)).map(T::exp)
+}
+
+/// The geometric coefficient of variation $ \sqrt{\exp(\sigma^2(\ln x_i)) - 1} $,
+/// based on the sample variance of the logarithms.
+/// Returns `None` if a sample is not positive.
+pub fn geometric_coefficient_of_variation<T>(xs: &[T]) -> Option<T>
+where
+    T: RealField + Ln + Exp + Copy,
+{
+    let variance = variance(&logarithms(xs)?, Some(VarianceBias::Sample))?;
+    Some((variance.exp() - T::one()).sqrt())
+}
+
+/// The [standard error of the mean](https://en.wikipedia.org/wiki/Standard_error#Standard_error_of_the_sample_mean),
+/// estimated by the sample standard deviation divided by $\sqrt{n}$.
+pub fn standard_error_of_mean<T>(xs: &[T]) -> Option<T>
+where
+    T: NumericField + Sqrt + Copy,
+{
+    let std = standard_deviation(xs, Some(VarianceBias::Sample))?;
+    Some(std / sample_size::<T>(xs.len()).sqrt())
+}
+
+pub trait StandardErrorOfMean<T> {
+    fn standard_error_of_mean(&self) -> Option<T>;
+}
+
+impl<T, S> StandardErrorOfMean<T> for S
+where
+    S: AsSlice<T>,
+    T: NumericField + Sqrt + Copy,
+{
+    fn standard_error_of_mean(&self) -> Option<T> {
+        standard_error_of_mean(self.as_slice())
     }
 }
 
 pub fn dot<T>(xs: &[T], ys: &[T]) -> Option<T>
 where
-    T: NumericField + From<i8> + Copy,
+    T: NumericField + Copy,
 {
     if xs.is_empty() || xs.len() != ys.len() {
         return None;
@@ -152,12 +546,13 @@ pub trait Dot<S, T> {
     fn dot(&self, ys: S) -> Option<T>;
 }
 
-impl<'a, T, S> Dot<S, T> for S
+impl<T, S, R> Dot<R, T> for S
 where
     S: AsSlice<T>,
-    T: 'a + NumericField + From<i8> + Copy,
+    R: AsSlice<T>,
+    T: NumericField + Copy,
 {
-    fn dot(&self, ys: S) -> Option<T> {
+    fn dot(&self, ys: R) -> Option<T> {
         dot(self.as_slice(), ys.as_slice())
     }
 }
@@ -166,44 +561,192 @@ where
 /// https://en.wikipedia.org/wiki/Sample_mean_and_covariance
 pub fn covariance<T>(xs: &[T], ys: &[T]) -> Option<T>
 where
-    T: NumericField + From<i8> + Copy,
+    T: NumericField + Copy,
 {
     if xs.len() != ys.len() || xs.len() <= 1 {
         return None;
     }
 
-    let x_mean = mean(xs)?;
-    let y_mean = mean(ys)?;
-
-    // TODO: bench it. maybe it's faster to use a single loop
-    let x_err: Vec<T> = xs.iter().map(|x| *x - x_mean).collect();
-    let y_err: Vec<T> = ys.iter().map(|y| *y - y_mean).collect();
-
-    let dot = dot(&x_err, &y_err)?;
-    let len = xs.len() as i8;
-    Some(dot / (T::from(len) - T::one()))
+    let c = sum_of_products(xs, ys)?;
+    Some(c / (sample_size::<T>(xs.len()) - T::one()))
 }
 
 pub trait Covariance<S, T> {
     fn covariance(&self, ys: S) -> Option<T>;
 }
 
-impl<'a, T, S> Covariance<S, T> for S
+impl<T, S, R> Covariance<R, T> for S
 where
     S: AsSlice<T>,
-    T: 'a + NumericField + From<i8> + Copy,
+    R: AsSlice<T>,
+    T: NumericField + Copy,
 {
-    fn covariance(&self, ys: S) -> Option<T> {
+    fn covariance(&self, ys: R) -> Option<T> {
         covariance(self.as_slice(), ys.as_slice())
     }
 }
 
-// TODO: add skewness
-// https://en.wikipedia.org/wiki/Skewness#Sample_skewness
+/// The sample [covariance matrix](https://en.wikipedia.org/wiki/Covariance_matrix) of several variables,
+/// provided as one slice of samples per variable, where entry $(i, j)$ is the `covariance` of the variables $i$ and $j$.
+///
+/// The means and the deviations from them are computed once per variable, so that for $k$ variables with
+/// $n$ samples each the means cost $O(k n)$ rather than $O(k^2 n)$ for pairwise `covariance`s;
+/// the $k (k + 1) / 2$ distinct entries are dot products of the deviations.
+///
+/// Returns `None` if there are no variables, the variables differ in length, or there are less than two samples.
+pub fn covariance_matrix<T>(columns: &[&[T]]) -> Option<Vec<Vec<T>>>
+where
+    T: NumericField + Copy,
+{
+    let n = columns.first()?.len();
+    if n <= 1 || columns.iter().any(|column| column.len() != n) {
+        return None;
+    }
+
+    let deviations = columns
+        .iter()
+        .map(|column| {
+            let column_mean = mean(column)?;
+            Some(column.iter().map(|x| *x - column_mean).collect::<Vec<T>>())
+        })
+        .collect::<Option<Vec<Vec<T>>>>()?;
+    // `n > 1`, hence the degrees of freedom are positive
+    let degrees_of_freedom = sample_size::<T>(n) - T::one();
+
+    let k = columns.len();
+    let mut matrix = vec![vec![T::zero(); k]; k];
+    for i in 0..k {
+        for j in i..k {
+            let entry = dot(&deviations[i], &deviations[j])? / degrees_of_freedom;
+            matrix[i][j] = entry;
+            matrix[j][i] = entry;
+        }
+    }
+    Some(matrix)
+}
+
+/// The sample size, the sums of squared deviations of both samples and their co-moment,
+/// accumulated in a single pass with the
+/// [extended Welford algorithm](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Covariance).
+/// Returns `None` if the iterators have different lengths.
+fn co_moments<T, I, J>(mut xs: I, mut ys: J) -> Option<(T, T, T, T)>
+where
+    I: Iterator<Item = T>,
+    J: Iterator<Item = T>,
+    T: NumericField + Copy,
+{
+    let mut n = T::zero();
+    let (mut x_mean, mut y_mean) = (T::zero(), T::zero());
+    let (mut x_m2, mut y_m2, mut c) = (T::zero(), T::zero(), T::zero());
+
+    loop {
+        let (x, y) = match (xs.next(), ys.next()) {
+            (Some(x), Some(y)) => (x, y),
+            (None, None) => return Some((n, x_m2, y_m2, c)),
+            _ => return None,
+        };
+
+        n = n + T::one();
+        let dx = x - x_mean;
+        let dy = y - y_mean;
+        x_mean = x_mean + dx / n;
+        y_mean = y_mean + dy / n;
+        x_m2 = x_m2 + dx * (x - x_mean);
+        y_m2 = y_m2 + dy * (y - y_mean);
+        c = c + dx * (y - y_mean);
+    }
+}
+
+/// The sample covariance as `covariance`, consuming two iterators in lockstep in a single pass.
+/// Returns `None` if fewer than two pairs are provided or the iterators have different lengths.
+pub fn covariance_iter<T, I, J>(xs: I, ys: J) -> Option<T>
+where
+    I: Iterator<Item = T>,
+    J: Iterator<Item = T>,
+    T: NumericField + Copy,
+{
+    let (n, _, _, c) = co_moments(xs, ys)?;
+    if n == T::zero() || n == T::one() {
+        return None;
+    }
+    Some(c / (n - T::one()))
+}
+
+/// The [Pearson correlation coefficient](https://en.wikipedia.org/wiki/Pearson_correlation_coefficient)
+/// $ r = cov(x, y) / (\sigma_x \sigma_y) $, consuming two iterators in lockstep in a single pass.
+/// Returns `None` if fewer than two pairs are provided, the iterators have different lengths or either sample is constant.
+pub fn correlation_iter<T, I, J>(xs: I, ys: J) -> Option<T>
+where
+    I: Iterator<Item = T>,
+    J: Iterator<Item = T>,
+    T: RealField + Copy,
+{
+    let (n, x_m2, y_m2, c) = co_moments(xs, ys)?;
+    if n == T::zero() || n == T::one() {
+        return None;
+    }
+    let scale = (x_m2 * y_m2).sqrt();
+    if scale == T::zero() {
+        return None;
+    }
+    Some(c / scale)
+}
+
+/// The second, third and fourth central moments $ m_k = \Sigma (x_i - \bar x)^k / n $,
+/// or `None` for less than two samples or a vanishing second moment.
+fn central_moments<T>(xs: &[T]) -> Option<(T, T, T)>
+where
+    T: NumericField + Copy,
+{
+    if xs.len() < 2 {
+        return None;
+    }
+
+    let x_mean = mean(xs)?;
+    let (m2, m3, m4) = xs
+        .iter()
+        .fold((T::zero(), T::zero(), T::zero()), |(m2, m3, m4), x| {
+            let d = *x - x_mean;
+            let d2 = d * d;
+            (m2 + d2, m3 + d2 * d, m4 + d2 * d2)
+        });
+
+    if m2 == T::zero() {
+        return None;
+    }
+    let n = sample_size::<T>(xs.len());
+    Some((m2 / n, m3 / n, m4 / n))
+}
+
+/// The (biased) [sample skewness](https://en.wikipedia.org/wiki/Skewness#Sample_skewness)
+/// $ g_1 = m_3 / m_2^{3/2} $, where $m_k$ are the central moments.
+/// Returns `None` for less than two samples or constant samples.
+pub fn skewness<T>(xs: &[T]) -> Option<T>
+where
+    T: RealField + Copy,
+{
+    let (m2, m3, _) = central_moments(xs)?;
+    Some(m3 / (m2 * m2.sqrt()))
+}
+
+/// The (biased) sample [excess kurtosis](https://en.wikipedia.org/wiki/Kurtosis#Sample_kurtosis)
+/// $ g_2 = m_4 / m_2^2 - 3 $, where $m_k$ are the central moments, such that a normal distribution has a kurtosis of zero.
+/// Returns `None` for less than two samples or constant samples.
+pub fn kurtosis<T>(xs: &[T]) -> Option<T>
+where
+    T: RealField + Copy,
+{
+    let (m2, _, m4) = central_moments(xs)?;
+    let three = T::one() + T::one() + T::one();
+    Some(m4 / (m2 * m2) - three)
+}
 
 #[cfg(test)]
 mod test {
-    use super::{Covariance, Dot, Mean, Sum, Variance, VarianceBias};
+    use super::{
+        CoefficientOfVariation, Covariance, Dot, Mean, StandardDeviation, StandardErrorOfMean, Sum,
+        Variance, VarianceBias,
+    };
 
     #[test]
     fn sum() {
@@ -235,6 +778,74 @@ mod test {
         assert_eq!(super::mean(&xs), Some(3.5));
     }
 
+    #[test]
+    fn sum_ref() {
+        use nmbrs_algebra::{AddIdentity, NumericSemiGroup};
+
+        // a type which is `Clone` but not `Copy`
+        #[derive(Debug, Clone, PartialEq)]
+        struct Digits(Vec<u8>);
+
+        impl std::ops::Add for Digits {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Digits(vec![self.0[0] + rhs.0[0]])
+            }
+        }
+
+        impl AddIdentity for Digits {
+            fn zero() -> Self {
+                Digits(vec![0])
+            }
+        }
+
+        impl NumericSemiGroup for Digits {}
+
+        let xs = vec![Digits(vec![1]), Digits(vec![2]), Digits(vec![3])];
+        assert_eq!(super::sum_ref(&xs), Some(Digits(vec![6])));
+        assert_eq!(xs.sum(), Some(Digits(vec![6])));
+        assert_eq!(super::sum_ref::<Digits>(&[]), None);
+
+        // coincides with `sum` for `Copy` types
+        let xs = [1.0, 2.5, -0.5];
+        assert_eq!(super::sum_ref(&xs), super::sum(&xs));
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn sum_ref_big_rational() {
+        use num_rational::BigRational;
+
+        let xs: Vec<BigRational> = [(1, 2), (1, 3), (1, 6)]
+            .iter()
+            .map(|(n, d)| BigRational::new((*n).into(), (*d).into()))
+            .collect();
+        assert_eq!(
+            super::sum_ref(&xs),
+            Some(BigRational::from_integer(1.into()))
+        );
+        assert_eq!(xs.sum(), Some(BigRational::from_integer(1.into())));
+    }
+
+    #[test]
+    fn sum_iter() {
+        assert_eq!(super::sum_iter(std::iter::empty::<f64>()), None);
+        assert_eq!(super::sum_iter(1..=9), Some(45));
+        assert_eq!(super::sum_iter([1.0, 2.0, 3.5].into_iter()), Some(6.5));
+    }
+
+    #[test]
+    fn mean_iter() {
+        use super::MeanExt;
+
+        assert_eq!(std::iter::empty::<f64>().mean(), None);
+        assert_eq!((1..=100).map(|i| i as f64).mean(), Some(50.5));
+
+        let xs = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        assert_eq!(super::mean_iter(xs.iter().copied()), super::mean(&xs));
+        assert_eq!(xs.iter().map(|x| x * 2.0).mean(), Some(7.0));
+    }
+
     #[test]
     fn population_variance() {
         let xs: Vec<f32> = Vec::with_capacity(0);
@@ -262,10 +873,28 @@ mod test {
             Some(2.0)
         );
 
-        let _xs = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        // let xs = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
         // assert_eq!(super::variance(&xs), Some(2.));
     }
 
+    #[test]
+    fn variance_with() {
+        let xs = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_eq!(xs.variance_with(VarianceBias::Sample), xs.sample_variance());
+        assert_eq!(
+            xs.variance_with(VarianceBias::Population),
+            xs.population_variance()
+        );
+        assert_eq!(xs.variance_with(VarianceBias::Population), Some(4.0));
+        assert_eq!(
+            xs.variance_with(VarianceBias::default()),
+            super::variance(&xs, None)
+        );
+
+        let xs = vec![1.0];
+        assert_eq!(xs.variance_with(VarianceBias::Sample), None);
+    }
+
     #[test]
     fn dot() {
         let xs = vec![1.0];
@@ -279,7 +908,11 @@ mod test {
         let xs = vec![1.0, 2.0, 3.0];
         let ys = vec![4.0, 5.0, 6.0];
         assert_eq!(super::dot(&xs, &ys), Some(32.0));
-        assert_eq!(super::dot(&xs, &ys), xs.dot(ys));
+        assert_eq!(super::dot(&xs, &ys), xs.dot(ys.clone()));
+
+        // operands of different types
+        assert_eq!(xs.dot(ys.as_slice()), Some(32.0));
+        assert_eq!(ys.as_slice().dot(xs.clone()), Some(32.0));
 
         let xs = vec![1.0, 2.0, 3.0, 4.0];
         let ys = vec![4.0, 5.0, 6.0, 7.0];
@@ -315,7 +948,7 @@ mod test {
         let xs = vec![1.0, 2.0, 3.0, 4.0, 5.0];
         let ys = vec![4.0, 5.0, 6.0, 7.0, 8.0];
         assert_eq!(super::covariance(&xs, &ys), Some(2.5));
-        assert_eq!(super::covariance(&xs, &ys), xs.covariance(ys));
+        assert_eq!(super::covariance(&xs, &ys), xs.covariance(ys.as_slice()));
 
         assert_eq!(super::covariance(&xs, &xs), xs.sample_variance());
 
@@ -324,6 +957,94 @@ mod test {
         // assert_eq!(super::covariance(&xs, &ys), Some(154.0));
     }
 
+    #[test]
+    fn skewness_kurtosis() {
+        // symmetric samples
+        let xs = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_abs_diff_eq!(super::skewness(&xs).unwrap(), 0.0, epsilon = EPSILON);
+        // m2 = 2, m4 = 6.8
+        assert_abs_diff_eq!(
+            super::kurtosis(&xs).unwrap(),
+            6.8 / 4.0 - 3.0,
+            epsilon = EPSILON
+        );
+
+        // right-skewed: m2 = 1.6875, m3 = 2.53125
+        let xs = [0.0, 0.0, 0.0, 3.0];
+        assert_abs_diff_eq!(
+            super::skewness(&xs).unwrap(),
+            2.53125 / 1.6875_f64.powf(1.5),
+            epsilon = EPSILON
+        );
+
+        assert_eq!(super::skewness(&[1.0]), None);
+        assert_eq!(super::kurtosis(&[2.0, 2.0, 2.0]), None);
+    }
+
+    #[test]
+    fn covariance_matrix() {
+        let xs = [2.1, 2.5, 3.6, 4.0, 5.2, 6.3];
+        let ys = [8.0, 10.0, 12.0, 14.0, 13.0, 20.0];
+        let zs = [-1.0, 0.5, 0.25, 3.0, -2.0, 1.0];
+        let columns: [&[f64]; 3] = [&xs, &ys, &zs];
+
+        let matrix = super::covariance_matrix(&columns).unwrap();
+        assert_eq!(matrix.len(), 3);
+        for (i, row) in matrix.iter().enumerate() {
+            assert_eq!(row.len(), 3);
+            for (j, entry) in row.iter().enumerate() {
+                // the entries agree with the pairwise covariances, which recompute both means each
+                let expected = super::covariance(columns[i], columns[j]).unwrap();
+                assert_abs_diff_eq!(*entry, expected, epsilon = 1e-14);
+                assert_eq!(*entry, matrix[j][i]);
+            }
+        }
+
+        assert_eq!(super::covariance_matrix::<f64>(&[]), None);
+        assert_eq!(super::covariance_matrix(&[&xs[..1]]), None);
+        assert_eq!(super::covariance_matrix(&[&xs[..], &ys[..3]]), None);
+    }
+
+    #[test]
+    fn covariance_iter() {
+        let xs = [2.1, 2.5, 3.6, 4.0, 5.2, 6.3];
+        let ys = [8.0, 10.0, 12.0, 14.0, 13.0, 20.0];
+
+        let cov = super::covariance_iter(xs.iter().copied(), ys.iter().copied()).unwrap();
+        assert_abs_diff_eq!(cov, super::covariance(&xs, &ys).unwrap(), epsilon = 1e-14);
+
+        assert_eq!(
+            super::covariance_iter([1.0].into_iter(), [2.0].into_iter()),
+            None
+        );
+        assert_eq!(
+            super::covariance_iter(xs.iter().copied(), ys.iter().skip(1).copied()),
+            None
+        );
+    }
+
+    #[test]
+    fn correlation_iter() {
+        let xs = [2.1, 2.5, 3.6, 4.0, 5.2, 6.3];
+        let ys = [8.0, 10.0, 12.0, 14.0, 13.0, 20.0];
+
+        let r = super::correlation_iter(xs.iter().copied(), ys.iter().copied()).unwrap();
+        let expected = super::covariance(&xs, &ys).unwrap()
+            / (super::standard_deviation(&xs, None).unwrap()
+                * super::standard_deviation(&ys, None).unwrap());
+        assert_abs_diff_eq!(r, expected, epsilon = 1e-14);
+
+        // perfectly (anti-)correlated
+        let r = super::correlation_iter(xs.iter().copied(), xs.iter().map(|x| 3.0 - 2.0 * x));
+        assert_abs_diff_eq!(r.unwrap(), -1.0, epsilon = 1e-14);
+
+        // constant sample
+        assert_eq!(
+            super::correlation_iter(xs.iter().copied(), [1.0; 6].into_iter()),
+            None
+        );
+    }
+
     use approx::assert_abs_diff_eq;
     const EPSILON: f64 = 1e-15;
 
@@ -348,6 +1069,122 @@ mod test {
         );
     }
 
+    #[test]
+    fn geometric_stats() {
+        // the logarithms are 0, ln(10) and 2 ln(10)
+        let xs = vec![1.0, 10.0, 100.0];
+        assert_abs_diff_eq!(super::geometric_mean(&xs).unwrap(), 10.0, epsilon = 1e-13);
+        assert_abs_diff_eq!(super::geometric_std(&xs).unwrap(), 10.0, epsilon = 1e-13);
+
+        let ln_10 = 10.0_f64.ln();
+        assert_abs_diff_eq!(
+            super::geometric_coefficient_of_variation(&xs).unwrap(),
+            ((ln_10 * ln_10).exp() - 1.0).sqrt(),
+            epsilon = 1e-12
+        );
+
+        // no spread
+        assert_abs_diff_eq!(super::geometric_std(&[5.0, 5.0]).unwrap(), 1.0);
+        assert_abs_diff_eq!(
+            super::geometric_coefficient_of_variation(&[5.0, 5.0]).unwrap(),
+            0.0
+        );
+
+        assert_eq!(super::geometric_mean(&[1.0, 0.0]), None);
+        assert_eq!(super::geometric_std(&[1.0, -2.0]), None);
+    }
+
+    #[test]
+    fn clamp_slice() {
+        let xs = vec![-5.0, 0.5, 1.0, 2.0, 100.0];
+        assert_eq!(
+            super::clamp_slice(&xs, 0.0, 10.0),
+            vec![0.0, 0.5, 1.0, 2.0, 10.0]
+        );
+        assert_eq!(
+            super::clamp_slice(&xs, 10.0, 0.0),
+            super::clamp_slice(&xs, 0.0, 10.0)
+        );
+        assert_eq!(super::clamp_slice(&[3, 7], 4, 6), vec![4, 6]);
+    }
+
+    #[test]
+    fn clamped_mean() {
+        // the outlier dominates the raw mean
+        let xs = vec![1.0, 2.0, 3.0, 2.0, 1000.0];
+        assert_eq!(super::mean(&xs), Some(201.6));
+        assert_eq!(super::clamped_mean(&xs, 0.0, 4.0), Some(2.4));
+
+        let empty: &[f64] = &[];
+        assert_eq!(super::clamped_mean(empty, 0.0, 4.0), None);
+    }
+
+    #[test]
+    fn normalize_range() {
+        let xs = [3.0, -1.0, 7.0, 5.0];
+        let ys = super::normalize_range(&xs, 0.0, 1.0).unwrap();
+        assert_eq!(ys, vec![0.5, 0.0, 1.0, 0.75]);
+
+        let ys = super::normalize_range(&xs, -0.3, 0.7).unwrap();
+        assert_eq!(ys.iter().copied().fold(f64::INFINITY, f64::min), -0.3);
+        assert_eq!(ys.iter().copied().fold(f64::NEG_INFINITY, f64::max), 0.7);
+
+        let mut zs = xs;
+        assert_eq!(
+            super::normalize_range_in_place(&mut zs, 10.0, 20.0),
+            Some(())
+        );
+        assert_eq!(zs, [15.0, 10.0, 20.0, 17.5]);
+
+        // integers are not multiplied by a truncated inverse of the range
+        assert_eq!(
+            super::normalize_range(&[0, 5, 10, 3], 0, 100),
+            Some(vec![0, 50, 100, 30])
+        );
+
+        assert_eq!(super::normalize_range::<f64>(&[], 0.0, 1.0), None);
+        assert_eq!(super::normalize_range(&[2.0, 2.0], 0.0, 1.0), None);
+        assert_eq!(super::normalize_range(&[1.0, f64::NAN], 0.0, 1.0), None);
+
+        let mut constant = [4.0, 4.0];
+        assert_eq!(
+            super::normalize_range_in_place(&mut constant, 0.0, 1.0),
+            None
+        );
+        assert_eq!(constant, [4.0, 4.0]);
+    }
+
+    #[test]
+    fn nan_mean_variance() {
+        let xs = [1.0, f64::NAN, 2.0, 4.0, f64::NAN, 5.0];
+        let finite = [1.0, 2.0, 4.0, 5.0];
+        assert!(super::mean(&xs).unwrap().is_nan());
+        assert_eq!(super::nan_mean(&xs), super::mean(&finite));
+        assert_eq!(super::nan_mean(&xs), Some(3.0));
+        assert_eq!(
+            super::nan_variance(&xs, Some(VarianceBias::Sample)),
+            super::variance(&finite, Some(VarianceBias::Sample))
+        );
+
+        assert_eq!(super::nan_mean(&[f64::NAN, f64::NAN]), None);
+        assert_eq!(super::nan_variance(&[f64::NAN], None), None);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn mean_rational() {
+        use num_rational::Rational64;
+
+        let xs = vec![
+            Rational64::new(1, 2),
+            Rational64::new(1, 3),
+            Rational64::new(1, 6),
+        ];
+        assert_eq!(super::mean(&xs), Some(Rational64::new(1, 3)));
+        assert_eq!(xs.mean(), Some(Rational64::new(1, 3)));
+        assert_eq!(super::sum(&xs), Some(Rational64::new(1, 1)));
+    }
+
     #[test]
     fn mean_0() {
         assert_eq!(super::mean::<f64>(&[]), None);
@@ -373,4 +1210,255 @@ mod test {
             epsilon = EPSILON
         );
     }
+
+    #[test]
+    fn sum_of_squares() {
+        // deviations -2, -1, 0, 1, 2 from the mean 3
+        let xs = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(super::sum_of_squares(&xs), Some(10.0));
+        assert_eq!(super::sum_of_squares(&[7.0]), Some(0.0));
+        assert_eq!(super::sum_of_squares::<f64>(&[]), None);
+    }
+
+    #[test]
+    fn sum_of_products() {
+        // deviations -2, -1, 0, 1, 2 and 4, 2, 0, -2, -4 from the means 3 and 6
+        let xs = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let ys = [10.0, 8.0, 6.0, 4.0, 2.0];
+        assert_eq!(super::sum_of_products(&xs, &ys), Some(-20.0));
+        assert_eq!(super::sum_of_products(&xs, &xs), super::sum_of_squares(&xs));
+        assert_eq!(super::sum_of_products(&xs, &ys[1..]), None);
+        assert_eq!(super::sum_of_products::<f64>(&[], &[]), None);
+    }
+
+    #[test]
+    fn variance_integers() {
+        let xs = [1, 2, 3, 4, 5_i32];
+        assert_eq!(
+            super::variance(&xs, Some(VarianceBias::Population)),
+            Some(2)
+        );
+        assert_eq!(super::variance(&xs, Some(VarianceBias::Sample)), Some(2));
+        assert_eq!(super::sum_of_squares(&xs), Some(10));
+        assert_eq!(super::variance(&[7_i64], Some(VarianceBias::Sample)), None);
+
+        let ys = [10, 8, 6, 4, 2_i64];
+        assert_eq!(super::covariance(&[1, 2, 3, 4, 5_i64], &ys), Some(-5));
+        assert_eq!(super::pooled_variance(&[&xs[..], &xs[..]]), Some(2));
+        assert_eq!(super::weighted_variance(&xs, &[1, 1, 1, 1, 1]), Some(2));
+
+        let columns: [&[i32]; 2] = [&[1, 2, 3, 4, 5], &[10, 8, 6, 4, 2]];
+        assert_eq!(
+            super::covariance_matrix(&columns),
+            Some(vec![vec![2, -5], vec![-5, 10]])
+        );
+    }
+
+    #[test]
+    fn variance_two_pass() {
+        // the textbook two-pass formula of the squared deviations from the mean
+        fn two_pass(xs: &[f64], bias: VarianceBias) -> f64 {
+            let mean = xs.iter().sum::<f64>() / xs.len() as f64;
+            let m2: f64 = xs.iter().map(|x| (mean - x) * (mean - x)).sum();
+            match bias {
+                VarianceBias::Population => m2 / xs.len() as f64,
+                VarianceBias::Sample => m2 / (xs.len() - 1) as f64,
+            }
+        }
+
+        let fixtures: [&[f64]; 4] = [
+            &[
+                5.376_671_395_461e-1,
+                1.833_885_014_595_086_5,
+                -2.258_846_861_003_648,
+                8.621_733_203_681_206e-1,
+                3.187_652_398_589_808e-1,
+                -1.307_688_296_305_273_4,
+                -4.335_920_223_056_835_6e-1,
+                3.426_244_665_386_499e-1,
+                3.578_396_939_725_760_5,
+                2.769_437_029_884_877,
+            ],
+            &[1.0, 2.0, 3.0, 4.0, 5.0],
+            &[2.0, 2.0, 2.0, 2.0, 2.0],
+            &[82., 91., 12., 92., 63., 9., 28., 55., 96., 97.],
+        ];
+        for xs in fixtures {
+            for bias in [VarianceBias::Population, VarianceBias::Sample] {
+                let expected = two_pass(xs, bias);
+                assert_abs_diff_eq!(
+                    super::variance(xs, Some(bias)).unwrap(),
+                    expected,
+                    epsilon = 1e-12 * expected.max(1.0)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn variance_large_offset() {
+        // a small spread around a large mean, whose sum accumulates rounding errors
+        let xs: Vec<f64> = (0..100_000)
+            .map(|i| 1e11 + [4.0, 7.0, 13.0, 16.0][i % 4] + 0.1 * (i % 7) as f64)
+            .collect();
+        // computed in exact rational arithmetic
+        let (sample, population) = (22.540_266_989_646_58, 22.540_041_586_976_68);
+
+        // the textbook two-pass formula loses precision by the rounding error of the mean
+        let mean = xs.iter().sum::<f64>() / xs.len() as f64;
+        let two_pass =
+            xs.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (xs.len() - 1) as f64;
+        assert!((two_pass - sample).abs() > 1e-6);
+
+        assert_abs_diff_eq!(
+            super::variance(&xs, Some(VarianceBias::Sample)).unwrap(),
+            sample,
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            super::variance(&xs, Some(VarianceBias::Population)).unwrap(),
+            population,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn variance_few_samples() {
+        let xs: &[f64] = &[];
+        assert_eq!(super::variance(xs, None), None);
+        assert_eq!(super::variance(&[1.0], Some(VarianceBias::Sample)), None);
+        assert_eq!(
+            super::variance(&[1.0], Some(VarianceBias::Population)),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn single_sample() {
+        // the sample estimators would divide by `n - 1 = 0`
+        let xs = vec![4.2];
+        assert_eq!(xs.sample_variance(), None);
+        assert_eq!(xs.population_variance(), Some(0.0));
+        assert_eq!(xs.sample_standard_deviation(), None);
+        assert_eq!(xs.population_standard_deviation(), Some(0.0));
+        assert_eq!(super::standard_error_of_mean(&xs), None);
+        assert_eq!(super::covariance(&xs, &xs), None);
+        assert_eq!(super::pooled_variance(&[xs.as_slice()]), None);
+    }
+
+    #[test]
+    fn large_sample_size() {
+        // sizes beyond `i8::MAX`
+        let xs: Vec<f64> = (0..1000).map(|i| (i % 2) as f64).collect();
+        assert_eq!(super::sample_size::<f64>(xs.len()), 1000.0);
+        assert_abs_diff_eq!(
+            super::covariance(&xs, &xs).unwrap(),
+            xs.sample_variance().unwrap(),
+            epsilon = 1e-15
+        );
+        assert_abs_diff_eq!(
+            super::standard_error_of_mean(&xs).unwrap(),
+            xs.sample_standard_deviation().unwrap() / 1000_f64.sqrt(),
+            epsilon = 1e-15
+        );
+    }
+
+    #[test]
+    fn standard_deviation1() {
+        let xs = vec![
+            5.376_671_395_461e-1,
+            1.833_885_014_595_086_5,
+            -2.258_846_861_003_648,
+            8.621_733_203_681_206e-1,
+            3.187_652_398_589_808e-1,
+            -1.307_688_296_305_273_4,
+            -4.335_920_223_056_835_6e-1,
+            3.426_244_665_386_499e-1,
+            3.578_396_939_725_760_5,
+            2.769_437_029_884_877,
+        ];
+        assert_abs_diff_eq!(
+            xs.sample_standard_deviation().unwrap(),
+            1.769_884_779_851_071,
+            epsilon = EPSILON
+        );
+        assert_abs_diff_eq!(
+            xs.population_standard_deviation().unwrap(),
+            1.679_060_130_118_521_7,
+            epsilon = EPSILON
+        );
+        assert_abs_diff_eq!(
+            super::coefficient_of_variation(&xs).unwrap(),
+            2.835_071_684_088_201_3,
+            epsilon = 1e-14
+        );
+        assert_eq!(
+            super::coefficient_of_variation(&xs),
+            xs.coefficient_of_variation()
+        );
+        assert_abs_diff_eq!(
+            super::standard_error_of_mean(&xs).unwrap(),
+            5.596_867_100_395_072e-1,
+            epsilon = EPSILON
+        );
+        assert_eq!(
+            super::standard_error_of_mean(&xs),
+            xs.standard_error_of_mean()
+        );
+    }
+
+    #[test]
+    fn standard_deviation_f32() {
+        let xs: Vec<f32> = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_eq!(xs.population_standard_deviation(), Some(2.0));
+        assert_eq!(
+            super::standard_deviation(&xs, Some(VarianceBias::Population)),
+            Some(2.0)
+        );
+    }
+
+    #[test]
+    fn coefficient_of_variation_zero_mean() {
+        let xs = vec![-1.0, 1.0];
+        assert_eq!(super::coefficient_of_variation(&xs), None);
+        assert_eq!(super::coefficient_of_variation::<f64>(&[]), None);
+    }
+
+    #[test]
+    fn pooled_variance() {
+        assert_eq!(super::pooled_variance::<f64>(&[]), None);
+        assert_eq!(super::pooled_variance(&[&[1.0][..], &[2.0][..]]), None);
+
+        let xs = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(
+            super::pooled_variance(&[&xs[..], &xs[..]]),
+            super::variance(&xs, Some(VarianceBias::Sample))
+        );
+
+        // (2 * 2.5 + 1 * 2.0) / (2 + 1)
+        let ys = [2.0, 4.0];
+        assert_eq!(
+            super::pooled_variance(&[&xs[..3], &ys[..], &[7.0][..]]),
+            Some(4.0 / 3.0)
+        );
+    }
+
+    #[test]
+    fn weighted_variance() {
+        assert_eq!(super::weighted_variance::<f64>(&[], &[]), None);
+        assert_eq!(super::weighted_variance(&[1.0, 2.0], &[1.0]), None);
+        assert_eq!(super::weighted_variance(&[1.0, 2.0], &[1.0, -1.0]), None);
+
+        let xs = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(
+            super::weighted_variance(&xs, &[2.0; 5]),
+            super::variance(&xs, Some(VarianceBias::Population))
+        );
+
+        // frequency weights correspond to repeated samples
+        assert_eq!(
+            super::weighted_variance(&[1.0, 3.0], &[3.0, 1.0]),
+            super::variance(&[1.0, 1.0, 1.0, 3.0], Some(VarianceBias::Population))
+        );
+    }
 }
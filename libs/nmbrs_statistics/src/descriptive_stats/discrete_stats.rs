@@ -0,0 +1,100 @@
+use crate::AsSlice;
+
+/*
+Discrete statistics provides routines for samples taking values in a discrete (countable) set.
+ */
+
+/// Counts the occurrences of each distinct value, in order of their first appearance.
+fn frequencies<T>(xs: &[T]) -> Vec<(T, usize)>
+where
+    T: PartialEq + Copy,
+{
+    let mut counts: Vec<(T, usize)> = Vec::new();
+    for x in xs {
+        match counts.iter_mut().find(|(value, _)| value == x) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((*x, 1)),
+        }
+    }
+    counts
+}
+
+/// All [modes](https://en.wikipedia.org/wiki/Mode_(statistics)), i.e. the most frequent values of the samples.
+/// In case of ties, all tied values are returned in the order of their first appearance.
+pub fn modes<T>(xs: &[T]) -> Vec<T>
+where
+    T: PartialEq + Copy,
+{
+    let counts = frequencies(xs);
+    let max_count = match counts.iter().map(|(_, count)| *count).max() {
+        Some(max_count) => max_count,
+        None => return Vec::with_capacity(0),
+    };
+
+    counts
+        .into_iter()
+        .filter(|(_, count)| *count == max_count)
+        .map(|(value, _)| value)
+        .collect()
+}
+
+/// The [mode](https://en.wikipedia.org/wiki/Mode_(statistics)), i.e. the most frequent value of the samples.
+/// In case of ties, the first appearing value is returned.
+pub fn mode<T>(xs: &[T]) -> Option<T>
+where
+    T: PartialEq + Copy,
+{
+    modes(xs).first().copied()
+}
+
+pub trait Mode<T> {
+    fn mode(&self) -> Option<T>;
+    fn modes(&self) -> Vec<T>;
+}
+
+impl<T, S> Mode<T> for S
+where
+    S: AsSlice<T>,
+    T: PartialEq + Copy,
+{
+    fn mode(&self) -> Option<T> {
+        mode(self.as_slice())
+    }
+
+    fn modes(&self) -> Vec<T> {
+        modes(self.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mode;
+
+    #[test]
+    fn mode() {
+        assert_eq!(super::mode::<i32>(&[]), None);
+        assert!(super::modes::<i32>(&[]).is_empty());
+
+        let xs = vec![1, 2, 2, 3];
+        assert_eq!(super::mode(&xs), Some(2));
+        assert_eq!(super::modes(&xs), vec![2]);
+        assert_eq!(super::mode(&xs), xs.mode());
+    }
+
+    #[test]
+    fn multimodal() {
+        let xs = vec![1, 2, 2, 3, 3];
+        assert_eq!(super::modes(&xs), vec![2, 3]);
+        assert_eq!(super::mode(&xs), Some(2));
+        assert_eq!(super::modes(&xs), xs.modes());
+
+        // first-seen order
+        let xs = vec![3, 1, 2, 2, 3];
+        assert_eq!(super::modes(&xs), vec![3, 2]);
+        assert_eq!(super::mode(&xs), Some(3));
+
+        // all values equally frequent
+        let xs = vec![1.5, 0.5];
+        assert_eq!(super::modes(&xs), vec![1.5, 0.5]);
+    }
+}
@@ -0,0 +1,129 @@
+use super::array_stats::dot;
+use nmbrs_algebra::{Abs, NumericGroup, NumericRing, RealField, Sqrt};
+
+/*
+Distances between two samples of equal length, interpreted as points in space.
+ */
+
+/// The [Euclidean distance](https://en.wikipedia.org/wiki/Euclidean_distance)
+/// $ \sqrt{\Sigma (x_i - y_i)^2} $.
+/// Returns `None` if the lengths do not match or the samples are empty.
+pub fn euclidean_distance<T>(xs: &[T], ys: &[T]) -> Option<T>
+where
+    T: NumericRing + Sqrt + Copy,
+{
+    if xs.is_empty() || xs.len() != ys.len() {
+        return None;
+    }
+
+    let squared = xs.iter().zip(ys.iter()).fold(T::zero(), |acc, (x, y)| {
+        let diff = *x - *y;
+        acc + diff * diff
+    });
+    Some(squared.sqrt())
+}
+
+/// The [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry)
+/// $ \Sigma |x_i - y_i| $.
+/// Returns `None` if the lengths do not match or the samples are empty.
+pub fn manhattan_distance<T>(xs: &[T], ys: &[T]) -> Option<T>
+where
+    T: NumericGroup + Abs + Copy,
+{
+    if xs.is_empty() || xs.len() != ys.len() {
+        return None;
+    }
+
+    let distance = xs
+        .iter()
+        .zip(ys.iter())
+        .fold(T::zero(), |acc, (x, y)| acc + (*x - *y).abs());
+    Some(distance)
+}
+
+/// The [Chebyshev distance](https://en.wikipedia.org/wiki/Chebyshev_distance)
+/// $ \max |x_i - y_i| $.
+/// Returns `None` if the lengths do not match or the samples are empty.
+pub fn chebyshev_distance<T>(xs: &[T], ys: &[T]) -> Option<T>
+where
+    T: NumericGroup + Abs + PartialOrd + Copy,
+{
+    if xs.is_empty() || xs.len() != ys.len() {
+        return None;
+    }
+
+    let distance = xs.iter().zip(ys.iter()).fold(T::zero(), |max, (x, y)| {
+        let diff = (*x - *y).abs();
+        if diff > max {
+            diff
+        } else {
+            max
+        }
+    });
+    Some(distance)
+}
+
+/// The [cosine similarity](https://en.wikipedia.org/wiki/Cosine_similarity)
+/// $ x \cdot y / (\|x\| \|y\|) $, that is the cosine of the angle between both samples.
+/// Returns `None` if the lengths do not match, the samples are empty or either has a norm of zero.
+pub fn cosine_similarity<T>(xs: &[T], ys: &[T]) -> Option<T>
+where
+    T: RealField + Copy,
+{
+    let xy = dot(xs, ys)?;
+    let x_norm = dot(xs, xs)?.sqrt();
+    let y_norm = dot(ys, ys)?.sqrt();
+    T::checked_div(xy, x_norm * y_norm)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn distances() {
+        let xs = [0.0, 0.0];
+        let ys = [3.0, 4.0];
+        assert_eq!(super::euclidean_distance(&xs, &ys), Some(5.0));
+        assert_eq!(super::manhattan_distance(&xs, &ys), Some(7.0));
+        assert_eq!(super::chebyshev_distance(&xs, &ys), Some(4.0));
+
+        // symmetric
+        assert_eq!(super::euclidean_distance(&ys, &xs), Some(5.0));
+        assert_eq!(super::manhattan_distance(&ys, &xs), Some(7.0));
+        assert_eq!(super::chebyshev_distance(&ys, &xs), Some(4.0));
+
+        let ys = [-3, 4];
+        assert_eq!(super::manhattan_distance(&[0, 0], &ys), Some(7));
+        assert_eq!(super::chebyshev_distance(&[0, 0], &ys), Some(4));
+    }
+
+    #[test]
+    fn cosine_similarity() {
+        let xs = [1.0, 2.0, 3.0];
+        assert_eq!(super::cosine_similarity(&xs, &xs), Some(1.0));
+        assert_eq!(super::cosine_similarity(&xs, &[2.0, 4.0, 6.0]), Some(1.0));
+        assert_eq!(
+            super::cosine_similarity(&xs, &[-1.0, -2.0, -3.0]),
+            Some(-1.0)
+        );
+
+        // orthogonal
+        assert_eq!(
+            super::cosine_similarity(&[1.0, 0.0], &[0.0, 2.0]),
+            Some(0.0)
+        );
+        assert_eq!(super::cosine_similarity(&xs, &[3.0, 0.0, -1.0]), Some(0.0));
+
+        assert_eq!(super::cosine_similarity(&xs, &[0.0; 3]), None);
+        assert_eq!(super::cosine_similarity(&xs, &[1.0, 2.0]), None);
+    }
+
+    #[test]
+    fn distances_invalid() {
+        let xs = [0.0, 0.0];
+        let ys = [3.0];
+        assert_eq!(super::euclidean_distance(&xs, &ys), None);
+        assert_eq!(super::manhattan_distance(&xs, &ys), None);
+        assert_eq!(super::chebyshev_distance(&xs, &ys), None);
+        assert_eq!(super::euclidean_distance::<f64>(&[], &[]), None);
+    }
+}
@@ -0,0 +1,129 @@
+use super::array_stats::{standard_deviation, VarianceBias};
+use super::sorted_array_stats::percentile;
+use std::f64::consts::PI;
+
+/// A [kernel density estimate](https://en.wikipedia.org/wiki/Kernel_density_estimation)
+/// $ \hat f(x) = \frac{1}{n h} \Sigma K(\frac{x - x_i}{h}) $ of the distribution of the samples $x_i$,
+/// a smooth alternative to the histogram.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KernelDensity {
+    xs: Vec<f64>,
+    bandwidth: f64,
+}
+
+impl KernelDensity {
+    /// The estimate with the Gaussian kernel $ K(u) = e^{-u^2 / 2} / \sqrt{2 \pi} $.
+    /// If no `bandwidth` is provided, it is chosen by
+    /// [Silverman's rule of thumb](https://en.wikipedia.org/wiki/Kernel_density_estimation#A_rule-of-thumb_bandwidth_estimator)
+    /// $ h = 0.9 \min(\sigma, IQR / 1.34) n^{-1/5} $.
+    ///
+    /// Returns `None` for empty samples, a non-positive bandwidth, or if the rule of thumb degenerates for constant samples.
+    pub fn gaussian(xs: &[f64], bandwidth: Option<f64>) -> Option<Self> {
+        if xs.is_empty() {
+            return None;
+        }
+
+        let bandwidth = match bandwidth {
+            Some(bandwidth) => bandwidth,
+            None => silverman_bandwidth(xs)?,
+        };
+        if !(bandwidth > 0.0 && bandwidth.is_finite()) {
+            return None;
+        }
+
+        Some(Self {
+            xs: xs.to_vec(),
+            bandwidth,
+        })
+    }
+
+    pub fn bandwidth(&self) -> f64 {
+        self.bandwidth
+    }
+
+    /// The estimated density at `x`.
+    pub fn eval(&self, x: f64) -> f64 {
+        let h = self.bandwidth;
+        let kernel_sum: f64 = self
+            .xs
+            .iter()
+            .map(|x_i| {
+                let u = (x - x_i) / h;
+                (-0.5 * u * u).exp()
+            })
+            .sum();
+        kernel_sum / (self.xs.len() as f64 * h * (2.0 * PI).sqrt())
+    }
+
+    /// The estimated density at `points` equidistant locations from `lo` to `hi`, e.g. for plotting.
+    pub fn evaluate_grid(&self, lo: f64, hi: f64, points: usize) -> Vec<(f64, f64)> {
+        match points {
+            0 => Vec::with_capacity(0),
+            1 => vec![(lo, self.eval(lo))],
+            _ => {
+                let dx = (hi - lo) / (points - 1) as f64;
+                (0..points)
+                    .map(|idx| {
+                        let x = lo + idx as f64 * dx;
+                        (x, self.eval(x))
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+fn silverman_bandwidth(xs: &[f64]) -> Option<f64> {
+    let std = standard_deviation(xs, Some(VarianceBias::Sample)).unwrap_or(0.0);
+
+    let mut sorted_xs = xs.to_vec();
+    sorted_xs.sort_by(|a, b| a.total_cmp(b));
+    let iqr = percentile(&sorted_xs, 0.75)? - percentile(&sorted_xs, 0.25)?;
+
+    // the interquartile range vanishes for samples with many ties
+    let spread = match (std > 0.0, iqr > 0.0) {
+        (true, true) => std.min(iqr / 1.34),
+        (true, false) => std,
+        (false, _) => return None,
+    };
+    Some(0.9 * spread * (xs.len() as f64).powf(-0.2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KernelDensity;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn kernel_density_integrates_to_one() {
+        let xs = [1.0, 2.0, 2.2, 2.5, 3.0, 3.1, 4.0, 7.5];
+        let kde = KernelDensity::gaussian(&xs, None).unwrap();
+        assert!(kde.bandwidth() > 0.0);
+
+        let grid = kde.evaluate_grid(-20.0, 30.0, 5_001);
+        let integral: f64 = grid
+            .windows(2)
+            .map(|w| (w[1].0 - w[0].0) * (w[0].1 + w[1].1) / 2.0)
+            .sum();
+        assert_abs_diff_eq!(integral, 1.0, epsilon = 1e-6);
+
+        // the peak is close to the cluster around 2.5 rather than the outlier
+        let (x_peak, _) = grid
+            .iter()
+            .copied()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap();
+        assert!((x_peak - 2.5).abs() < 0.5);
+    }
+
+    #[test]
+    fn kernel_density_bandwidth() {
+        let kde = KernelDensity::gaussian(&[0.0], Some(1.0)).unwrap();
+        assert_abs_diff_eq!(kde.eval(0.0), 1.0 / (2.0 * std::f64::consts::PI).sqrt());
+
+        assert_eq!(KernelDensity::gaussian(&[], None), None);
+        assert_eq!(KernelDensity::gaussian(&[1.0, 2.0], Some(0.0)), None);
+        assert_eq!(KernelDensity::gaussian(&[1.0, 1.0], None), None);
+        assert_eq!(KernelDensity::gaussian(&[1.0], None), None);
+    }
+}
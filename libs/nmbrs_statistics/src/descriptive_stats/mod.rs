@@ -1,9 +1,29 @@
 mod array_stats;
+mod discrete_stats;
+mod distance;
+mod kernel_density;
+mod rank_stats;
 mod sorted_array_stats;
+mod vector_stats;
 
 pub use array_stats::{
-    covariance, mean, sum, variance, Covariance, Mean, Sum, Variance, VarianceBias,
+    clamp_slice, clamped_mean, coefficient_of_variation, correlation_iter, covariance,
+    covariance_iter, covariance_matrix, dot, geometric_coefficient_of_variation, geometric_mean,
+    geometric_std, kurtosis, mean, mean_iter, nan_mean, nan_variance, normalize_range,
+    normalize_range_in_place, pooled_variance, skewness, standard_deviation,
+    standard_error_of_mean, sum, sum_iter, sum_of_products, sum_of_squares, sum_ref, variance,
+    weighted_variance, CoefficientOfVariation, Covariance, Dot, Mean, MeanExt, StandardDeviation,
+    StandardErrorOfMean, Sum, Variance, VarianceBias,
 };
+pub use discrete_stats::{mode, modes, Mode};
+pub use distance::{chebyshev_distance, cosine_similarity, euclidean_distance, manhattan_distance};
+pub use kernel_density::KernelDensity;
+pub use rank_stats::{kendall_tau, rank, TieMethod};
+pub use sorted_array_stats::{
+    is_sorted_ascending, percentile, percentile_desc, percentile_percent, percentile_rank,
+    weighted_percentile, BoxPlotStats, IntoSorted, Percentile, Sorted,
+};
+pub use vector_stats::{componentwise_mean, componentwise_variance};
 
 /* TODOs:
 - splt into descriptive and inferential stats and ordered and unordered stats
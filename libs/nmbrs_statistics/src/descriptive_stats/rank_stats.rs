@@ -0,0 +1,179 @@
+use std::cmp::Ordering;
+
+/*
+Rank statistics provides routines which only depend on the relative order of the samples.
+ */
+
+/// The strategy to assign [ranks](https://en.wikipedia.org/wiki/Ranking#Strategies_for_handling_ties) to tied samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieMethod {
+    /// Tied samples receive the average of the ranks they span ("fractional ranking").
+    Average,
+    /// Tied samples receive the lowest of the ranks they span ("competition ranking").
+    Min,
+    /// Tied samples receive the highest of the ranks they span.
+    Max,
+    /// Tied samples receive the same rank and the next distinct sample the following one.
+    Dense,
+    /// Tied samples receive distinct ranks in the order of their appearance.
+    Ordinal,
+}
+
+/// The 1-based ranks of the samples, aligned with the input order, where ties are handled according to `ties`.
+///
+/// NOTE: Incomparable samples (such as `NaN`) are treated as ties.
+pub fn rank<T>(xs: &[T], ties: TieMethod) -> Vec<f64>
+where
+    T: PartialOrd,
+{
+    let n = xs.len();
+    let is_tied = |i: usize, j: usize| {
+        xs[i].partial_cmp(&xs[j]).unwrap_or(Ordering::Equal) == Ordering::Equal
+    };
+
+    // stable sort to keep the order of appearance for ordinal ranks
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| xs[i].partial_cmp(&xs[j]).unwrap_or(Ordering::Equal));
+
+    let mut ranks = vec![0.0; n];
+    let mut start = 0;
+    let mut dense_rank = 0.0;
+    while start < n {
+        let mut end = start + 1;
+        while end < n && is_tied(order[start], order[end]) {
+            end += 1;
+        }
+        dense_rank += 1.0;
+
+        // the group spans the ranks `start + 1..=end`
+        for (pos, &idx) in order.iter().enumerate().take(end).skip(start) {
+            ranks[idx] = match ties {
+                TieMethod::Average => (start + 1 + end) as f64 / 2.0,
+                TieMethod::Min => (start + 1) as f64,
+                TieMethod::Max => end as f64,
+                TieMethod::Dense => dense_rank,
+                TieMethod::Ordinal => (pos + 1) as f64,
+            };
+        }
+        start = end;
+    }
+
+    ranks
+}
+
+/// [Kendall's tau-b](https://en.wikipedia.org/wiki/Kendall_rank_correlation_coefficient#Tau-b)
+/// rank correlation coefficient, which corrects for ties in the denominator:
+/// $ \tau_B = (n_c - n_d) / \sqrt{(n_0 - n_1)(n_0 - n_2)} $
+/// where $n_c$ ($n_d$) is the number of concordant (discordant) pairs, $n_0 = n(n-1)/2$
+/// and $n_1$ ($n_2$) the number of pairs tied in `xs` (`ys`).
+///
+/// Returns `None` for mismatched or too short inputs, if either sample is constant, or if samples cannot be compared.
+///
+/// NOTE: This is the straightforward $O(n^2)$ pair counting; an $O(n \log n)$ merge-sort-based version could follow.
+pub fn kendall_tau<T>(xs: &[T], ys: &[T]) -> Option<f64>
+where
+    T: PartialOrd,
+{
+    let n = xs.len();
+    if n != ys.len() || n < 2 {
+        return None;
+    }
+
+    let mut concordant: usize = 0;
+    let mut discordant: usize = 0;
+    let mut ties_x: usize = 0;
+    let mut ties_y: usize = 0;
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let ord_x = xs[i].partial_cmp(&xs[j])?;
+            let ord_y = ys[i].partial_cmp(&ys[j])?;
+
+            match (ord_x, ord_y) {
+                (Ordering::Equal, Ordering::Equal) => {
+                    ties_x += 1;
+                    ties_y += 1;
+                }
+                (Ordering::Equal, _) => ties_x += 1,
+                (_, Ordering::Equal) => ties_y += 1,
+                _ if ord_x == ord_y => concordant += 1,
+                _ => discordant += 1,
+            }
+        }
+    }
+
+    let n_pairs = (n * (n - 1) / 2) as f64;
+    let denominator = ((n_pairs - ties_x as f64) * (n_pairs - ties_y as f64)).sqrt();
+    if denominator == 0.0 {
+        return None;
+    }
+
+    Some((concordant as f64 - discordant as f64) / denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TieMethod;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn rank_ties() {
+        let xs = [10, 20, 20, 30];
+        assert_eq!(
+            super::rank(&xs, TieMethod::Average),
+            vec![1.0, 2.5, 2.5, 4.0]
+        );
+        assert_eq!(super::rank(&xs, TieMethod::Min), vec![1.0, 2.0, 2.0, 4.0]);
+        assert_eq!(super::rank(&xs, TieMethod::Max), vec![1.0, 3.0, 3.0, 4.0]);
+        assert_eq!(super::rank(&xs, TieMethod::Dense), vec![1.0, 2.0, 2.0, 3.0]);
+        assert_eq!(
+            super::rank(&xs, TieMethod::Ordinal),
+            vec![1.0, 2.0, 3.0, 4.0]
+        );
+    }
+
+    #[test]
+    fn rank_input_order() {
+        let xs = [3.0, 1.0, 2.0, 1.0];
+        assert_eq!(
+            super::rank(&xs, TieMethod::Average),
+            vec![4.0, 1.5, 3.0, 1.5]
+        );
+        assert_eq!(
+            super::rank(&xs, TieMethod::Ordinal),
+            vec![4.0, 1.0, 3.0, 2.0]
+        );
+        assert!(super::rank::<f64>(&[], TieMethod::Average).is_empty());
+    }
+
+    #[test]
+    fn kendall_tau_invalid() {
+        assert_eq!(super::kendall_tau::<f64>(&[], &[]), None);
+        assert_eq!(super::kendall_tau(&[1.0], &[2.0]), None);
+        assert_eq!(super::kendall_tau(&[1.0, 2.0], &[2.0]), None);
+        // constant sample
+        assert_eq!(super::kendall_tau(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0]), None);
+    }
+
+    #[test]
+    fn kendall_tau_perfect() {
+        let xs = [1, 2, 3, 4, 5];
+        let ys = [10, 20, 30, 40, 50];
+        assert_eq!(super::kendall_tau(&xs, &ys), Some(1.0));
+
+        let ys = [50, 40, 30, 20, 10];
+        assert_eq!(super::kendall_tau(&xs, &ys), Some(-1.0));
+    }
+
+    #[test]
+    fn kendall_tau_ties() {
+        // n_c = 3, n_d = 1, one tie in x and one in y out of 6 pairs
+        let xs = [1.0, 2.0, 2.0, 3.0];
+        let ys = [1.0, 3.0, 2.0, 2.0];
+        assert_abs_diff_eq!(
+            super::kendall_tau(&xs, &ys).unwrap(),
+            (3.0 - 1.0) / 5.0,
+            epsilon = 1e-15
+        );
+    }
+}
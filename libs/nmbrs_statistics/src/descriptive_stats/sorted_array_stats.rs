@@ -1,5 +1,9 @@
 use crate::AsSlice;
 use nmbrs_algebra::{MidPoint, NumericField};
+use std::cmp::Ordering;
+
+/// The message of the debug assertions on the order of the samples.
+const UNSORTED: &str = "the samples must be sorted in ascending order";
 
 /*
 Sorted array statistics provides routines optimized for an array sorting ascendingly.
@@ -7,33 +11,137 @@ Especially order-statistics are very efficient this way, some even with constant
 Source: https://numerics.mathdotnet.com/DescriptiveStatistics
 */
 
+/// Whether the samples are sorted in ascending order, allowing for equal adjacent samples,
+/// as assumed by the functions on _sorted_ samples. Incomparable samples such as `NaN` are not considered sorted.
+pub fn is_sorted_ascending<T: PartialOrd>(xs: &[T]) -> bool {
+    xs.windows(2).all(|w| w[0] <= w[1])
+}
+
 /// Calculates the [empirical percentile](https://en.wikipedia.org/wiki/Percentile) of the _sorted_ samples.
 /// The samples are assumed to be sorted in ascending order and level is assumed to be in the range `[0, 1]`.
+/// Debug builds assert the order.
 pub fn percentile<T>(sorted_xs: &[T], level: f64) -> Option<T>
+where
+    T: NumericField + MidPoint + PartialOrd + Copy,
+{
+    debug_assert!(is_sorted_ascending(sorted_xs), "{UNSORTED}");
+    percentile_by(sorted_xs.len(), level, |idx| sorted_xs[idx])
+}
+
+/// The `percentile` of samples sorted in _descending_ order, e.g. a top-k list.
+/// Agrees with `percentile` of the same samples sorted ascendingly.
+pub fn percentile_desc<T>(sorted_desc_xs: &[T], level: f64) -> Option<T>
+where
+    T: NumericField + MidPoint + PartialOrd + Copy,
+{
+    debug_assert!(
+        sorted_desc_xs.windows(2).all(|w| w[0] >= w[1]),
+        "the samples must be sorted in descending order"
+    );
+    let n = sorted_desc_xs.len();
+    percentile_by(n, level, |idx| sorted_desc_xs[n - 1 - idx])
+}
+
+/// The `percentile` of `n` samples, where `ascending(idx)` is the sample at position `idx` in ascending order.
+fn percentile_by<T>(n: usize, level: f64, ascending: impl Fn(usize) -> T) -> Option<T>
 where
     T: NumericField + MidPoint + Copy,
 {
     if !(0.0..=1.0).contains(&level) {
         return None;
     }
-    if sorted_xs.is_empty() {
+    if n == 0 {
         return None;
     }
 
-    let n = sorted_xs.len();
-
     // NOTE: have to add `-1` below due to (mathematical) idx start of 1 (rather than 0)
     let candidate_idx: f64 = n as f64 * level;
     let floored: usize = candidate_idx.floor() as usize;
 
     // case candidate is an integer
     if candidate_idx == floored as f64 {
-        let idx_bottom = (floored - 1).max(0);
-        let idx_top = floored.min(n);
-        return Some(sorted_xs[idx_bottom].mid_point(sorted_xs[idx_top]));
+        let idx_bottom = floored.saturating_sub(1);
+        let idx_top = floored.min(n - 1);
+        return Some(ascending(idx_bottom).mid_point(ascending(idx_top)));
+    }
+    let idx = ((candidate_idx + 1.0).floor().min(n as f64) as usize).saturating_sub(1);
+    Some(ascending(idx))
+}
+
+/// The [percentile rank](https://en.wikipedia.org/wiki/Percentile_rank) of `value`, i.e. the fraction of the _sorted_
+/// samples less than or equal to `value`, which is the [empirical distribution function](https://en.wikipedia.org/wiki/Empirical_distribution_function)
+/// evaluated at `value`. The samples are assumed to be sorted in ascending order, which allows for a binary search.
+///
+/// Samples equal to `value` are counted entirely, hence the rank of the smallest sample is $1 / n$ rather than $0$.
+/// Values below all samples have rank `0`, values above or equal to the largest sample have rank `1`.
+/// Returns `None` for empty samples or an incomparable `value` such as `NaN`.
+pub fn percentile_rank<T>(sorted_xs: &[T], value: T) -> Option<f64>
+where
+    T: PartialOrd,
+{
+    debug_assert!(is_sorted_ascending(sorted_xs), "{UNSORTED}");
+    if sorted_xs.is_empty() || value.partial_cmp(&value).is_none() {
+        return None;
+    }
+    let n_below_or_equal = sorted_xs.partition_point(|x| *x <= value);
+    Some(n_below_or_equal as f64 / sorted_xs.len() as f64)
+}
+
+/// The `percentile` for a `percent` in the range `[0, 100]`, e.g. `95.0` for the 95th percentile.
+/// Returns `None` if `percent` is out of range.
+pub fn percentile_percent<T>(sorted_xs: &[T], percent: f64) -> Option<T>
+where
+    T: NumericField + MidPoint + PartialOrd + Copy,
+{
+    if !(0.0..=100.0).contains(&percent) {
+        return None;
+    }
+    percentile(sorted_xs, percent / 100.0)
+}
+
+/// Calculates the weighted percentile of the _sorted_ samples, where each sample carries a non-negative weight,
+/// e.g. its frequency.
+/// Returns the sample at which the normalized cumulative weight crosses `level`, or the midpoint with the
+/// subsequent sample if it hits `level` exactly. Hence equal weights reproduce the `percentile`.
+///
+/// Returns `None` if the lengths do not match, a weight is negative, or the weights sum up to zero.
+pub fn weighted_percentile<T>(sorted_xs: &[T], weights: &[T], level: f64) -> Option<T>
+where
+    T: NumericField + MidPoint + PartialOrd + Into<f64> + Copy,
+{
+    debug_assert!(is_sorted_ascending(sorted_xs), "{UNSORTED}");
+    if !(0.0..=1.0).contains(&level) || sorted_xs.len() != weights.len() {
+        return None;
+    }
+
+    let weights: Vec<f64> = weights.iter().map(|w| (*w).into()).collect();
+    if weights.iter().any(|w| *w < 0.0) {
+        return None;
+    }
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return None;
+    }
+
+    let target = total * level;
+    let mut cumulative = 0.0;
+    for (idx, w) in weights.iter().enumerate() {
+        if *w == 0.0 {
+            continue;
+        }
+        cumulative += w;
+        if cumulative < target {
+            continue;
+        }
+        if cumulative == target {
+            // the subsequent sample with positive weight, if any
+            if let Some(next_idx) = (idx + 1..weights.len()).find(|j| weights[*j] > 0.0) {
+                return Some(sorted_xs[idx].mid_point(sorted_xs[next_idx]));
+            }
+        }
+        return Some(sorted_xs[idx]);
     }
-    let idx = ((candidate_idx + 1.0).floor().min(n as f64) as usize - 1).max(0);
-    Some(sorted_xs[idx])
+    None
 }
 
 pub trait Percentile<T> {
@@ -52,19 +160,123 @@ pub trait Percentile<T> {
     }
 }
 
-impl<'a, T, S> Percentile<T> for S
+impl<T, S> Percentile<T> for S
 where
     S: AsSlice<T>,
-    T: 'a + NumericField + MidPoint + Copy,
+    T: NumericField + MidPoint + PartialOrd + Copy,
 {
     fn percentile(&self, level: f64) -> Option<T> {
         percentile(self.as_slice(), level)
     }
 }
 
+/// Samples which are guaranteed to be sorted ascendingly, so that the order statistics such as the `Percentile`s
+/// can be taken without sorting them first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sorted<T>(Vec<T>);
+
+impl<T> Sorted<T> {
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+/// Provides the `Percentile` and the other statistics of the samples.
+impl<T> AsSlice<T> for Sorted<T> {
+    fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+}
+
+pub trait IntoSorted<T> {
+    /// Sorts the samples ascendingly once.
+    ///
+    /// NOTE: Incomparable samples (such as `NaN`) are treated as equal to any other sample.
+    fn into_sorted(self) -> Sorted<T>;
+}
+
+impl<T, S> IntoSorted<T> for S
+where
+    S: AsSlice<T>,
+    T: PartialOrd + Clone,
+{
+    fn into_sorted(self) -> Sorted<T> {
+        let mut xs = self.as_slice().to_vec();
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        Sorted(xs)
+    }
+}
+
+/// The statistics of a [box plot](https://en.wikipedia.org/wiki/Box_plot), that is the
+/// [five-number summary](https://en.wikipedia.org/wiki/Five-number_summary) along with the fences
+/// $ q_1 - k \cdot IQR $ and $ q_3 + k \cdot IQR $, where $IQR = q_3 - q_1$ is the interquartile range,
+/// and the samples beyond the fences.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxPlotStats<T> {
+    pub min: T,
+    pub q1: T,
+    pub median: T,
+    pub q3: T,
+    pub max: T,
+    pub lower_fence: T,
+    pub upper_fence: T,
+    /// The samples below the lower or above the upper fence, in ascending order.
+    pub outliers: Vec<T>,
+}
+
+impl<T> BoxPlotStats<T>
+where
+    T: NumericField + MidPoint + PartialOrd + Copy,
+{
+    /// Computes the box plot statistics of the _sorted_ samples, where the quartiles are the `percentile`s
+    /// and the fences are Tukey's with $k = 1.5$, i.e. $IQR + IQR / 2$ beyond the quartiles.
+    ///
+    /// Returns `None` for empty samples.
+    pub fn from_sorted(sorted_xs: &[T]) -> Option<Self> {
+        let two = T::one() + T::one();
+        Self::from_sorted_by(sorted_xs, |iqr| iqr + iqr / two)
+    }
+
+    /// Computes the box plot statistics as `from_sorted`, where `k` scales the interquartile range for the fences.
+    pub fn from_sorted_with_scale(sorted_xs: &[T], k: T) -> Option<Self> {
+        Self::from_sorted_by(sorted_xs, |iqr| k * iqr)
+    }
+
+    /// The box plot statistics with the fences `whisker(iqr)` beyond the quartiles.
+    fn from_sorted_by(sorted_xs: &[T], whisker: impl FnOnce(T) -> T) -> Option<Self> {
+        debug_assert!(is_sorted_ascending(sorted_xs), "{UNSORTED}");
+        let min = *sorted_xs.first()?;
+        let max = *sorted_xs.last()?;
+        let q1 = percentile(sorted_xs, 0.25)?;
+        let median = percentile(sorted_xs, 0.5)?;
+        let q3 = percentile(sorted_xs, 0.75)?;
+
+        let whisker = whisker(q3 - q1);
+        let lower_fence = q1 - whisker;
+        let upper_fence = q3 + whisker;
+        let outliers = sorted_xs
+            .iter()
+            .filter(|x| **x < lower_fence || **x > upper_fence)
+            .copied()
+            .collect();
+
+        Some(Self {
+            min,
+            q1,
+            median,
+            q3,
+            max,
+            lower_fence,
+            upper_fence,
+            outliers,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Percentile;
+    use super::{BoxPlotStats, IntoSorted, Percentile};
+    use crate::AsSlice;
 
     #[test]
     fn percentile() {
@@ -84,4 +296,175 @@ mod tests {
         assert_eq!(quartile_trd, Some(92.0));
         assert_eq!(super::percentile(&samples, 0.75), samples.p75());
     }
+
+    #[test]
+    fn is_sorted_ascending() {
+        assert!(super::is_sorted_ascending(&[1.0, 2.0, 2.0, 3.0]));
+        assert!(super::is_sorted_ascending(&[5, 5, 5]));
+        assert!(super::is_sorted_ascending::<f64>(&[]));
+        assert!(super::is_sorted_ascending(&[1.0]));
+        assert!(!super::is_sorted_ascending(&[1.0, 3.0, 2.0]));
+        assert!(!super::is_sorted_ascending(&[1.0, f64::NAN]));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "sorted in ascending order")]
+    fn percentile_unsorted() {
+        super::percentile(&[3.0, 1.0, 2.0], 0.5);
+    }
+
+    #[test]
+    fn percentile_percent() {
+        let mut samples = vec![82., 91., 12., 92., 63., 9., 28., 55., 96., 97.];
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert_eq!(
+            super::percentile_percent(&samples, 50.0),
+            super::percentile(&samples, 0.5)
+        );
+        assert_eq!(
+            super::percentile_percent(&samples, 25.0),
+            super::percentile(&samples, 0.25)
+        );
+        assert_eq!(super::percentile_percent(&samples, 100.0), Some(97.0));
+        assert_eq!(super::percentile_percent(&samples, 150.0), None);
+        assert_eq!(super::percentile_percent(&samples, -1.0), None);
+    }
+
+    #[test]
+    fn percentile_desc() {
+        let mut samples = vec![82., 91., 12., 92., 63., 9., 28., 55., 96., 97., 55.];
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut samples_desc = samples.clone();
+        samples_desc.reverse();
+
+        for level in [0.0, 0.1, 0.25, 0.5, 0.6, 0.75, 0.99, 1.0] {
+            assert_eq!(
+                super::percentile_desc(&samples_desc, level),
+                super::percentile(&samples, level)
+            );
+        }
+        assert_eq!(super::percentile_desc(&[4, 3, 1], 0.5), Some(3));
+        assert_eq!(super::percentile_desc(&samples_desc, 1.5), None);
+        assert_eq!(super::percentile_desc::<f64>(&[], 0.5), None);
+    }
+
+    #[test]
+    fn percentile_rank() {
+        // an odd number of samples, such that the median is a sample
+        let samples: Vec<f64> = (0..101).map(|i| ((i * 37) % 101) as f64 / 10.0).collect();
+        let sorted = samples.into_sorted().into_inner();
+        let median = super::percentile(&sorted, 0.5).unwrap();
+        assert_eq!(median, 5.0);
+        let rank = super::percentile_rank(&sorted, median).unwrap();
+        assert!((rank - 0.5).abs() <= 1.0 / sorted.len() as f64);
+
+        // ties are counted entirely
+        let sorted = [1, 2, 2, 2, 5];
+        assert_eq!(super::percentile_rank(&sorted, 2), Some(0.8));
+        assert_eq!(super::percentile_rank(&sorted, 3), Some(0.8));
+        assert_eq!(super::percentile_rank(&sorted, 1), Some(0.2));
+        assert_eq!(super::percentile_rank(&sorted, 0), Some(0.0));
+        assert_eq!(super::percentile_rank(&sorted, 5), Some(1.0));
+        assert_eq!(super::percentile_rank(&sorted, 9), Some(1.0));
+
+        assert_eq!(super::percentile_rank(&[1.0, 2.0], f64::NAN), None);
+        assert_eq!(super::percentile_rank(&[], 1.0), None);
+    }
+
+    #[test]
+    fn percentile_integers() {
+        let samples = vec![1, 3, 4, 10];
+        assert_eq!(samples.median(), Some(3));
+        assert_eq!(samples.p25(), Some(2));
+
+        let samples = vec![i32::MAX - 2, i32::MAX];
+        assert_eq!(samples.median(), Some(i32::MAX - 1));
+    }
+
+    #[test]
+    fn into_sorted() {
+        let samples = vec![82., 91., 12., 92., 63., 9., 28., 55., 96., 97.];
+        let mut sorted_samples = samples.clone();
+        sorted_samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let sorted = samples.as_slice().into_sorted();
+        assert_eq!(sorted.as_slice(), sorted_samples.as_slice());
+
+        let sorted = samples.into_sorted();
+        assert_eq!(sorted.median(), super::percentile(&sorted_samples, 0.5));
+        assert_eq!(sorted.median(), Some(72.5));
+        assert_eq!(sorted.p25(), Some(28.0));
+        assert_eq!(sorted.into_inner(), sorted_samples);
+    }
+
+    #[test]
+    fn box_plot_stats() {
+        let mut samples = vec![82., 91., 12., 92., 63., 9., 28., 55., 96., 97., 350.];
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let stats = BoxPlotStats::from_sorted(&samples).unwrap();
+        assert_eq!(stats.min, 9.0);
+        assert_eq!(stats.max, 350.0);
+        assert_eq!(Some(stats.q1), samples.p25());
+        assert_eq!(Some(stats.median), samples.median());
+        assert_eq!(Some(stats.q3), samples.p75());
+
+        let iqr = stats.q3 - stats.q1;
+        assert_eq!(stats.lower_fence, stats.q1 - 1.5 * iqr);
+        assert_eq!(stats.upper_fence, stats.q3 + 1.5 * iqr);
+        assert_eq!(stats.outliers, vec![350.0]);
+
+        // the fences scaled by 3, beyond which only extreme outliers lie
+        let stats = BoxPlotStats::from_sorted_with_scale(&samples, 3.0).unwrap();
+        assert_eq!(stats.upper_fence, stats.q3 + 3.0 * iqr);
+        assert_eq!(stats.outliers, vec![350.0]);
+
+        // integer samples
+        let stats = BoxPlotStats::from_sorted(&[1, 2, 3, 4, 5, 6, 7, 8, 40]).unwrap();
+        assert_eq!((stats.q1, stats.q3), (3, 7));
+        assert_eq!((stats.lower_fence, stats.upper_fence), (-3, 13));
+        assert_eq!(stats.outliers, vec![40]);
+
+        assert_eq!(BoxPlotStats::<f64>::from_sorted(&[]), None);
+    }
+
+    #[test]
+    fn weighted_percentile() {
+        let mut samples = vec![82., 91., 12., 92., 63., 9., 28., 55., 96., 97.];
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        // equal weights reproduce the percentile
+        let weights = vec![2.0; samples.len()];
+        for level in [0.0, 0.1, 0.25, 0.33, 0.5, 0.75, 0.9, 1.0] {
+            assert_eq!(
+                super::weighted_percentile(&samples, &weights, level),
+                super::percentile(&samples, level)
+            );
+        }
+
+        // the weights act as frequencies
+        let xs = vec![1.0, 2.0, 3.0];
+        let weights = vec![1.0, 1.0, 2.0];
+        let expanded = vec![1.0, 2.0, 3.0, 3.0];
+        for level in [0.25, 0.5, 0.6, 0.75] {
+            assert_eq!(
+                super::weighted_percentile(&xs, &weights, level),
+                super::percentile(&expanded, level)
+            );
+        }
+    }
+
+    #[test]
+    fn weighted_percentile_invalid() {
+        let xs = vec![1.0, 2.0, 3.0];
+        assert_eq!(super::weighted_percentile(&xs, &[1.0, 1.0], 0.5), None);
+        assert_eq!(super::weighted_percentile(&xs, &[0.0; 3], 0.5), None);
+        assert_eq!(
+            super::weighted_percentile(&xs, &[1.0, -1.0, 1.0], 0.5),
+            None
+        );
+        assert_eq!(super::weighted_percentile(&xs, &[1.0; 3], 1.5), None);
+    }
 }
@@ -0,0 +1,80 @@
+use super::array_stats::{mean, variance, VarianceBias};
+use nmbrs_algebra::Vector;
+
+/*
+Statistics of samples of vectors, such as the states of a vector-valued ODE, computed per component.
+ */
+
+/// The `mean` of each component across the vectors `xs`.
+/// Returns `None` for empty samples.
+pub fn componentwise_mean<const D: usize>(xs: &[Vector<D, f64>]) -> Option<Vector<D, f64>> {
+    componentwise(xs, mean)
+}
+
+/// The `variance` of each component across the vectors `xs`, see `variance` for the choice of `ty`.
+/// Returns `None` for less samples than the estimator requires.
+pub fn componentwise_variance<const D: usize>(
+    xs: &[Vector<D, f64>],
+    ty: Option<VarianceBias>,
+) -> Option<Vector<D, f64>> {
+    componentwise(xs, |c| variance(c, ty))
+}
+
+/// Applies the statistic `stat` to each component, i.e. to the columns of `xs`.
+fn componentwise<const D: usize>(
+    xs: &[Vector<D, f64>],
+    stat: impl Fn(&[f64]) -> Option<f64>,
+) -> Option<Vector<D, f64>> {
+    let rows: Vec<[f64; D]> = xs.iter().map(|x| (*x).into()).collect();
+    let mut result = [0.0; D];
+    for (idx, r) in result.iter_mut().enumerate() {
+        let column: Vec<f64> = rows.iter().map(|row| row[idx]).collect();
+        *r = stat(&column)?;
+    }
+    Some(Vector::new(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::descriptive_stats::{mean, variance, VarianceBias};
+    use nmbrs_algebra::Vector;
+
+    #[test]
+    fn componentwise_mean_and_variance() {
+        let xs = [
+            Vector::new([1.0, -2.0]),
+            Vector::new([2.0, 0.5]),
+            Vector::new([4.0, 3.0]),
+            Vector::new([5.0, -1.5]),
+        ];
+        let first = [1.0, 2.0, 4.0, 5.0];
+        let second = [-2.0, 0.5, 3.0, -1.5];
+
+        let means: [f64; 2] = super::componentwise_mean(&xs).unwrap().into();
+        assert_eq!(means, [mean(&first).unwrap(), mean(&second).unwrap()]);
+
+        for ty in [Some(VarianceBias::Population), Some(VarianceBias::Sample)] {
+            let variances: [f64; 2] = super::componentwise_variance(&xs, ty).unwrap().into();
+            assert_eq!(
+                variances,
+                [
+                    variance(&first, ty).unwrap(),
+                    variance(&second, ty).unwrap()
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn componentwise_undersized() {
+        let xs: [Vector<3, f64>; 0] = [];
+        assert_eq!(super::componentwise_mean(&xs), None);
+
+        let xs = [Vector::new([1.0, 2.0])];
+        assert_eq!(super::componentwise_variance(&xs, None), None);
+        assert_eq!(
+            super::componentwise_variance(&xs, Some(VarianceBias::Population)),
+            Some(Vector::new([0.0, 0.0]))
+        );
+    }
+}
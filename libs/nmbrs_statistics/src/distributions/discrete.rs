@@ -0,0 +1,188 @@
+use super::special::ln_gamma;
+
+/// The logarithm of the binomial coefficient $\binom{n}{k}$, which does not overflow for large `n`.
+fn ln_binomial_coefficient(n: u64, k: u64) -> f64 {
+    ln_gamma(n as f64 + 1.0) - ln_gamma(k as f64 + 1.0) - ln_gamma((n - k) as f64 + 1.0)
+}
+
+/// The [binomial distribution](https://en.wikipedia.org/wiki/Binomial_distribution)
+/// of the number of successes in `n` independent trials with success probability `p`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binomial {
+    n: u64,
+    p: f64,
+}
+
+impl Binomial {
+    /// Returns `None` unless `p` is in the range `[0, 1]`.
+    pub fn new(n: u64, p: f64) -> Option<Self> {
+        if !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+        Some(Self { n, p })
+    }
+
+    /// The number of trials.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// The success probability of a trial.
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// The probability mass function $ \binom{n}{k} p^k (1 - p)^{n - k} $.
+    pub fn pmf(&self, k: u64) -> f64 {
+        if k > self.n {
+            return 0.0;
+        }
+        // the degenerate cases are excluded, as `0 * ln(0)` is undefined
+        if self.p == 0.0 {
+            return if k == 0 { 1.0 } else { 0.0 };
+        }
+        if self.p == 1.0 {
+            return if k == self.n { 1.0 } else { 0.0 };
+        }
+
+        let ln_pmf = ln_binomial_coefficient(self.n, k)
+            + k as f64 * self.p.ln()
+            + (self.n - k) as f64 * (-self.p).ln_1p();
+        ln_pmf.exp()
+    }
+
+    /// The cumulative distribution function $ P(X \leq k) $.
+    pub fn cdf(&self, k: u64) -> f64 {
+        if k >= self.n {
+            return 1.0;
+        }
+        (0..=k).map(|i| self.pmf(i)).sum::<f64>().min(1.0)
+    }
+
+    /// The mean $np$.
+    pub fn mean(&self) -> f64 {
+        self.n as f64 * self.p
+    }
+
+    /// The variance $np(1 - p)$.
+    pub fn variance(&self) -> f64 {
+        self.n as f64 * self.p * (1.0 - self.p)
+    }
+}
+
+/// The [Poisson distribution](https://en.wikipedia.org/wiki/Poisson_distribution)
+/// of the number of events occurring in a fixed interval with rate `lambda`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+impl Poisson {
+    /// Returns `None` unless `lambda` is positive and finite.
+    pub fn new(lambda: f64) -> Option<Self> {
+        if !lambda.is_finite() || lambda <= 0.0 {
+            return None;
+        }
+        Some(Self { lambda })
+    }
+
+    /// The rate $\lambda$.
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// The probability mass function $ \frac{\lambda^k e^{-\lambda}}{k!} $.
+    pub fn pmf(&self, k: u64) -> f64 {
+        let ln_pmf = k as f64 * self.lambda.ln() - self.lambda - ln_gamma(k as f64 + 1.0);
+        ln_pmf.exp()
+    }
+
+    /// The cumulative distribution function $ P(X \leq k) $.
+    ///
+    /// NOTE: The pmf is summed over $0, ..., k$, hence the cost is linear in `k`.
+    pub fn cdf(&self, k: u64) -> f64 {
+        (0..=k).map(|i| self.pmf(i)).sum::<f64>().min(1.0)
+    }
+
+    /// The mean $\lambda$.
+    pub fn mean(&self) -> f64 {
+        self.lambda
+    }
+
+    /// The variance $\lambda$.
+    pub fn variance(&self) -> f64 {
+        self.lambda
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Binomial, Poisson};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn binomial() {
+        assert!(Binomial::new(10, 1.5).is_none());
+        assert!(Binomial::new(10, -0.1).is_none());
+
+        let binomial = Binomial::new(10, 0.3).unwrap();
+        assert_eq!((binomial.n(), binomial.p()), (10, 0.3));
+        assert_abs_diff_eq!(binomial.pmf(3), 0.266_827_932, epsilon = 1e-9);
+        assert_eq!(binomial.pmf(11), 0.0);
+        assert_eq!(binomial.cdf(10), 1.0);
+
+        // the moments of the pmf
+        let mean: f64 = (0..=10).map(|k| k as f64 * binomial.pmf(k)).sum();
+        let variance: f64 = (0..=10)
+            .map(|k| (k as f64 - mean).powi(2) * binomial.pmf(k))
+            .sum();
+        assert_abs_diff_eq!(mean, binomial.mean(), epsilon = 1e-12);
+        assert_abs_diff_eq!(variance, binomial.variance(), epsilon = 1e-12);
+        assert_abs_diff_eq!(binomial.mean(), 3.0, epsilon = 1e-15);
+        assert_abs_diff_eq!(binomial.variance(), 2.1, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn binomial_large_n() {
+        let binomial = Binomial::new(10_000, 0.5).unwrap();
+        let total: f64 = (0..=10_000).map(|k| binomial.pmf(k)).sum();
+        assert_abs_diff_eq!(total, 1.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(
+            binomial.cdf(4_999) + binomial.pmf(5_000) / 2.0,
+            0.5,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn binomial_degenerate() {
+        let binomial = Binomial::new(5, 0.0).unwrap();
+        assert_eq!(binomial.pmf(0), 1.0);
+        assert_eq!(binomial.pmf(1), 0.0);
+
+        let binomial = Binomial::new(5, 1.0).unwrap();
+        assert_eq!(binomial.pmf(5), 1.0);
+        assert_eq!(binomial.cdf(4), 0.0);
+    }
+
+    #[test]
+    fn poisson() {
+        assert!(Poisson::new(0.0).is_none());
+        assert!(Poisson::new(f64::INFINITY).is_none());
+
+        let poisson = Poisson::new(4.0).unwrap();
+        assert_eq!(poisson.lambda(), 4.0);
+        assert_abs_diff_eq!(poisson.pmf(2), 0.146_525_111, epsilon = 1e-9);
+        assert_abs_diff_eq!(poisson.cdf(2), 0.238_103_306, epsilon = 1e-9);
+
+        let total: f64 = (0..100).map(|k| poisson.pmf(k)).sum();
+        assert_abs_diff_eq!(total, 1.0, epsilon = 1e-12);
+
+        let mean: f64 = (0..100).map(|k| k as f64 * poisson.pmf(k)).sum();
+        let variance: f64 = (0..100)
+            .map(|k| (k as f64 - mean).powi(2) * poisson.pmf(k))
+            .sum();
+        assert_abs_diff_eq!(mean, poisson.mean(), epsilon = 1e-12);
+        assert_abs_diff_eq!(variance, poisson.variance(), epsilon = 1e-12);
+    }
+}
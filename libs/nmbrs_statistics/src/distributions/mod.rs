@@ -0,0 +1,9 @@
+mod discrete;
+mod normal;
+mod special;
+mod student_t;
+
+pub use discrete::{Binomial, Poisson};
+pub use normal::Normal;
+pub use special::{erf, erfc, ln_gamma, regularized_incomplete_beta};
+pub use student_t::StudentT;
@@ -0,0 +1,208 @@
+use super::special::erfc;
+use std::f64::consts::{PI, SQRT_2};
+
+/// The [normal (or Gaussian) distribution](https://en.wikipedia.org/wiki/Normal_distribution)
+/// with mean $\mu$ and standard deviation $\sigma$.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+    mean: f64,
+    std: f64,
+}
+
+impl Normal {
+    /// Returns `None` unless the standard deviation is positive and finite.
+    pub fn new(mean: f64, std: f64) -> Option<Self> {
+        if !mean.is_finite() || !std.is_finite() || std <= 0.0 {
+            return None;
+        }
+        Some(Self { mean, std })
+    }
+
+    /// The mean $\mu$.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// The standard deviation $\sigma$.
+    pub fn std(&self) -> f64 {
+        self.std
+    }
+
+    /// The standard normal distribution with mean `0` and standard deviation `1`.
+    pub fn standard() -> Self {
+        Self {
+            mean: 0.0,
+            std: 1.0,
+        }
+    }
+
+    /// The probability density function
+    /// $ \frac{1}{\sigma \sqrt{2\pi}} e^{-\frac{1}{2} (\frac{x - \mu}{\sigma})^2} $.
+    pub fn pdf(&self, x: f64) -> f64 {
+        let z = (x - self.mean) / self.std;
+        (-0.5 * z * z).exp() / (self.std * (2.0 * PI).sqrt())
+    }
+
+    /// The cumulative distribution function
+    /// $ \Phi(x) = \frac{1}{2} \operatorname{erfc}(-\frac{x - \mu}{\sigma \sqrt{2}}) $.
+    pub fn cdf(&self, x: f64) -> f64 {
+        let z = (x - self.mean) / self.std;
+        0.5 * erfc(-z / SQRT_2)
+    }
+
+    /// The inverse of the cumulative distribution function, also known as quantile function.
+    /// Returns `None` if `p` is not in the range `[0, 1]`.
+    ///
+    /// Uses [Acklam's algorithm](https://web.archive.org/web/20151030215612/http://home.online.no/~pjacklam/notes/invnorm/)
+    /// with a relative error of $1.15 \cdot 10^{-9}$, refined by a single step of Halley's method.
+    pub fn inverse_cdf(&self, p: f64) -> Option<f64> {
+        if !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+        if p == 0.0 {
+            return Some(f64::NEG_INFINITY);
+        }
+        if p == 1.0 {
+            return Some(f64::INFINITY);
+        }
+
+        let z = standard_quantile(p);
+        Some(self.mean + self.std * z)
+    }
+}
+
+impl Default for Normal {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// The quantile of the standard normal distribution for `p` in `(0, 1)`.
+fn standard_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+
+    let z = if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    };
+
+    // refinement by Halley's rational method
+    let e = 0.5 * erfc(-z / SQRT_2) - p;
+    let u = e * (2.0 * PI).sqrt() * (z * z / 2.0).exp();
+    z - u / (1.0 + z * u / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Normal;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn new() {
+        assert!(Normal::new(0.0, 0.0).is_none());
+        assert!(Normal::new(0.0, -1.0).is_none());
+        assert!(Normal::new(f64::NAN, 1.0).is_none());
+        assert_eq!(Normal::new(0.0, 1.0), Some(Normal::standard()));
+
+        let normal = Normal::new(2.0, 3.0).unwrap();
+        assert_eq!((normal.mean(), normal.std()), (2.0, 3.0));
+    }
+
+    #[test]
+    fn pdf() {
+        let normal = Normal::standard();
+        assert_abs_diff_eq!(
+            normal.pdf(0.0),
+            1.0 / (2.0 * std::f64::consts::PI).sqrt(),
+            epsilon = 1e-15
+        );
+        assert_eq!(normal.pdf(1.5), normal.pdf(-1.5));
+
+        let normal = Normal::new(2.0, 3.0).unwrap();
+        assert_abs_diff_eq!(normal.pdf(1.0), 0.125_794_409_230_997_72, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn cdf() {
+        let normal = Normal::new(2.0, 3.0).unwrap();
+        assert_eq!(normal.cdf(2.0), 0.5);
+
+        for x in [0.1, 0.5, 1.0, 2.5, 5.0, 10.0] {
+            assert_abs_diff_eq!(
+                normal.cdf(2.0 + x) + normal.cdf(2.0 - x),
+                1.0,
+                epsilon = 1e-15
+            );
+        }
+
+        assert_abs_diff_eq!(
+            Normal::standard().cdf(1.96),
+            0.975_002_104_851_779_6,
+            epsilon = 1e-15
+        );
+    }
+
+    #[test]
+    fn inverse_cdf() {
+        let normal = Normal::standard();
+        assert_eq!(normal.inverse_cdf(-0.1), None);
+        assert_eq!(normal.inverse_cdf(1.1), None);
+        assert_eq!(normal.inverse_cdf(0.0), Some(f64::NEG_INFINITY));
+        assert_eq!(normal.inverse_cdf(1.0), Some(f64::INFINITY));
+        assert_abs_diff_eq!(normal.inverse_cdf(0.5).unwrap(), 0.0, epsilon = 1e-15);
+        assert_abs_diff_eq!(
+            normal.inverse_cdf(0.975).unwrap(),
+            1.959_963_984_540_053_6,
+            epsilon = 1e-12
+        );
+
+        let normal = Normal::new(-1.0, 0.5).unwrap();
+        for x in [-4.0, -2.5, -1.0, -0.3, 0.0, 0.7] {
+            assert_abs_diff_eq!(
+                normal.inverse_cdf(normal.cdf(x)).unwrap(),
+                x,
+                epsilon = 1e-6
+            );
+        }
+    }
+}
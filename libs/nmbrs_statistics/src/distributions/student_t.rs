@@ -0,0 +1,149 @@
+use super::normal::Normal;
+use super::special::{ln_gamma, regularized_incomplete_beta};
+use std::f64::consts::PI;
+
+/// The [Student's t-distribution](https://en.wikipedia.org/wiki/Student%27s_t-distribution)
+/// with $\nu$ degrees of freedom, e.g. of the standardized mean of $\nu + 1$ normally distributed samples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StudentT {
+    degrees_of_freedom: f64,
+}
+
+impl StudentT {
+    /// Returns `None` unless the degrees of freedom are positive and finite.
+    pub fn new(degrees_of_freedom: f64) -> Option<Self> {
+        if !degrees_of_freedom.is_finite() || degrees_of_freedom <= 0.0 {
+            return None;
+        }
+        Some(Self { degrees_of_freedom })
+    }
+
+    /// The degrees of freedom $\nu$.
+    pub fn degrees_of_freedom(&self) -> f64 {
+        self.degrees_of_freedom
+    }
+
+    /// The probability density function
+    /// $ \frac{\Gamma(\frac{\nu + 1}{2})}{\sqrt{\nu \pi} \Gamma(\frac{\nu}{2})} (1 + \frac{x^2}{\nu})^{-\frac{\nu + 1}{2}} $.
+    pub fn pdf(&self, x: f64) -> f64 {
+        let nu = self.degrees_of_freedom;
+        let ln_norm = ln_gamma((nu + 1.0) / 2.0) - ln_gamma(nu / 2.0) - 0.5 * (nu * PI).ln();
+        (ln_norm - (nu + 1.0) / 2.0 * (x * x / nu).ln_1p()).exp()
+    }
+
+    /// The cumulative distribution function, by means of the regularized incomplete beta function
+    /// $ \frac{1}{2} I_{\nu / (\nu + x^2)}(\frac{\nu}{2}, \frac{1}{2}) $ for negative $x$, and by symmetry otherwise.
+    pub fn cdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        let nu = self.degrees_of_freedom;
+        let tail = 0.5 * regularized_incomplete_beta(nu / 2.0, 0.5, nu / (nu + x * x));
+        if x < 0.0 {
+            tail
+        } else {
+            1.0 - tail
+        }
+    }
+
+    /// The inverse of the cumulative distribution function, also known as quantile function.
+    /// Returns `None` if `p` is not in the range `[0, 1]`.
+    ///
+    /// The quantile is bracketed, starting from the normal quantile, and refined by bisection.
+    pub fn inverse_cdf(&self, p: f64) -> Option<f64> {
+        if !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+        if p == 0.0 {
+            return Some(f64::NEG_INFINITY);
+        }
+        if p == 1.0 {
+            return Some(f64::INFINITY);
+        }
+
+        // the t-distribution has heavier tails than the normal one, so its quantile is further out
+        let z = Normal::standard().inverse_cdf(p)?;
+        let (mut lower, mut upper) = if z < 0.0 {
+            (2.0 * z, 0.0)
+        } else {
+            (0.0, 2.0 * z)
+        };
+        while self.cdf(lower) > p {
+            lower *= 2.0;
+        }
+        while self.cdf(upper) < p {
+            upper *= 2.0;
+        }
+
+        while upper - lower > f64::EPSILON * upper.abs().max(lower.abs()).max(1.0) {
+            let mid = 0.5 * (lower + upper);
+            if self.cdf(mid) < p {
+                lower = mid;
+            } else {
+                upper = mid;
+            }
+        }
+        Some(0.5 * (lower + upper))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StudentT;
+    use crate::distributions::Normal;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn new() {
+        assert!(StudentT::new(0.0).is_none());
+        assert!(StudentT::new(-2.0).is_none());
+        assert!(StudentT::new(f64::INFINITY).is_none());
+        assert_eq!(StudentT::new(3.0).unwrap().degrees_of_freedom(), 3.0);
+    }
+
+    #[test]
+    fn pdf_cdf() {
+        // with one degree of freedom, the t-distribution is the Cauchy distribution
+        let cauchy = StudentT::new(1.0).unwrap();
+        for x in [-3.0, -0.5, 0.0, 1.0, 10.0] {
+            assert_abs_diff_eq!(
+                cauchy.pdf(x),
+                1.0 / (std::f64::consts::PI * (1.0 + x * x)),
+                epsilon = 1e-14
+            );
+            assert_abs_diff_eq!(
+                cauchy.cdf(x),
+                0.5 + x.atan() / std::f64::consts::PI,
+                epsilon = 1e-14
+            );
+        }
+
+        let t = StudentT::new(5.0).unwrap();
+        assert_eq!(t.cdf(0.0), 0.5);
+        assert_abs_diff_eq!(t.cdf(1.3) + t.cdf(-1.3), 1.0, epsilon = 1e-15);
+
+        // approaches the normal distribution for many degrees of freedom
+        let t = StudentT::new(1e6).unwrap();
+        assert_abs_diff_eq!(t.cdf(1.5), Normal::standard().cdf(1.5), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn inverse_cdf() {
+        // two-sided 95% critical values
+        for (nu, expected) in [
+            (1.0, 12.706_204_736_174_7),
+            (4.0, 2.776_445_105_197_793),
+            (7.0, 2.364_624_251_010_299),
+            (29.0, 2.045_229_642_132_703),
+        ] {
+            let t = StudentT::new(nu).unwrap();
+            assert_abs_diff_eq!(t.inverse_cdf(0.975).unwrap(), expected, epsilon = 1e-9);
+            assert_abs_diff_eq!(t.inverse_cdf(0.025).unwrap(), -expected, epsilon = 1e-9);
+        }
+
+        let t = StudentT::new(3.0).unwrap();
+        assert_eq!(t.inverse_cdf(1.5), None);
+        assert_eq!(t.inverse_cdf(0.0), Some(f64::NEG_INFINITY));
+        assert_abs_diff_eq!(t.inverse_cdf(0.5).unwrap(), 0.0, epsilon = 1e-12);
+    }
+}
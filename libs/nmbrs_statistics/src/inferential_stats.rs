@@ -0,0 +1,103 @@
+use crate::descriptive_stats::{mean, standard_error_of_mean};
+use crate::distributions::{Normal, StudentT};
+
+/*
+Inferential statistics provides routines to draw conclusions about a population from samples.
+ */
+
+/// The [Wilson score interval](https://en.wikipedia.org/wiki/Binomial_proportion_confidence_interval#Wilson_score_interval)
+/// for the success probability of a binomial proportion with confidence `level`:
+/// $ \frac{\hat p + \frac{z^2}{2n}}{1 + \frac{z^2}{n}} \pm \frac{z}{1 + \frac{z^2}{n}} \sqrt{\frac{\hat p (1 - \hat p)}{n} + \frac{z^2}{4n^2}} $
+/// where $\hat p$ is the observed proportion and $z$ the $1 - (1 - level) / 2$ quantile of the standard normal distribution.
+/// In contrast to the normal approximation it remains within `[0, 1]` and behaves well for proportions close to `0` or `1`.
+///
+/// Returns `None` if there are no `trials`, more `successes` than `trials`, or `level` is not in `(0, 1)`.
+pub fn wilson_interval(successes: u64, trials: u64, level: f64) -> Option<(f64, f64)> {
+    if trials == 0 || successes > trials || !(level > 0.0 && level < 1.0) {
+        return None;
+    }
+
+    let z = Normal::standard().inverse_cdf(1.0 - (1.0 - level) / 2.0)?;
+    let n = trials as f64;
+    let p = successes as f64 / n;
+    let z2_n = z * z / n;
+
+    let center = (p + z2_n / 2.0) / (1.0 + z2_n);
+    let half_width = z / (1.0 + z2_n) * (p * (1.0 - p) / n + z2_n / (4.0 * n)).sqrt();
+    Some((
+        (center - half_width).max(0.0),
+        (center + half_width).min(1.0),
+    ))
+}
+
+/// The [confidence interval](https://en.wikipedia.org/wiki/Confidence_interval) for the mean of normally
+/// distributed samples with confidence `level`: $ \bar x \pm t \cdot SE $, where $SE$ is the `standard_error_of_mean`
+/// and $t$ the $1 - (1 - level) / 2$ quantile of the Student's t-distribution with $n - 1$ degrees of freedom.
+///
+/// Returns `None` for less than two samples or if `level` is not in `(0, 1)`.
+pub fn mean_confidence_interval(xs: &[f64], level: f64) -> Option<(f64, f64)> {
+    if xs.len() < 2 || !(level > 0.0 && level < 1.0) {
+        return None;
+    }
+
+    let t = StudentT::new((xs.len() - 1) as f64)?.inverse_cdf(1.0 - (1.0 - level) / 2.0)?;
+    let center = mean(xs)?;
+    let half_width = t * standard_error_of_mean(xs)?;
+    Some((center - half_width, center + half_width))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn wilson_interval() {
+        let (lower, upper) = super::wilson_interval(8, 10, 0.95).unwrap();
+        assert_abs_diff_eq!(lower, 0.4902, epsilon = 1e-4);
+        assert_abs_diff_eq!(upper, 0.9433, epsilon = 1e-4);
+
+        // remains within [0, 1] for extreme proportions
+        let (lower, upper) = super::wilson_interval(0, 20, 0.95).unwrap();
+        assert_abs_diff_eq!(lower, 0.0, epsilon = 1e-15);
+        assert_abs_diff_eq!(upper, 0.1611, epsilon = 1e-4);
+
+        let (lower, upper) = super::wilson_interval(20, 20, 0.95).unwrap();
+        assert_abs_diff_eq!(lower, 1.0 - 0.1611, epsilon = 1e-4);
+        assert_abs_diff_eq!(upper, 1.0, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn wilson_interval_invalid() {
+        assert_eq!(super::wilson_interval(0, 0, 0.95), None);
+        assert_eq!(super::wilson_interval(11, 10, 0.95), None);
+        assert_eq!(super::wilson_interval(8, 10, 0.0), None);
+        assert_eq!(super::wilson_interval(8, 10, 1.0), None);
+        assert_eq!(super::wilson_interval(8, 10, f64::NAN), None);
+    }
+
+    #[test]
+    fn mean_confidence_interval() {
+        // scipy.stats.t.interval(0.95, df=9, loc=mean, scale=sem) for 1, ..., 10
+        let xs: Vec<f64> = (1..=10).map(|i| i as f64).collect();
+        let (lower, upper) = super::mean_confidence_interval(&xs, 0.95).unwrap();
+        assert_abs_diff_eq!(lower, 3.334_149, epsilon = 1e-3);
+        assert_abs_diff_eq!(upper, 7.665_851, epsilon = 1e-3);
+
+        let xs = [2.1, 2.5, 1.9, 2.8, 2.4, 2.2, 2.6, 2.0];
+        let (lower, upper) = super::mean_confidence_interval(&xs, 0.95).unwrap();
+        assert_abs_diff_eq!(lower, 2.050_260, epsilon = 1e-3);
+        assert_abs_diff_eq!(upper, 2.574_740, epsilon = 1e-3);
+
+        // narrower for a lower confidence
+        let (lower_90, upper_90) = super::mean_confidence_interval(&xs, 0.9).unwrap();
+        assert!(lower < lower_90 && upper_90 < upper);
+    }
+
+    #[test]
+    fn mean_confidence_interval_invalid() {
+        assert_eq!(super::mean_confidence_interval(&[], 0.95), None);
+        assert_eq!(super::mean_confidence_interval(&[1.0], 0.95), None);
+        assert_eq!(super::mean_confidence_interval(&[1.0, 2.0], 0.0), None);
+        assert_eq!(super::mean_confidence_interval(&[1.0, 2.0], 1.0), None);
+    }
+}
@@ -1,5 +1,10 @@
-mod descriptive_stats;
+pub mod descriptive_stats;
+pub mod distributions;
+pub mod inferential_stats;
+pub mod online_stats;
+pub mod regression;
 mod samples;
+pub mod timeseries;
 
 pub use samples::AsSlice;
 // use algebra::{MidPoint, NumericField, NumericSemiGroup};
@@ -0,0 +1,731 @@
+use nmbrs_algebra::{NumericField, RealField};
+
+/*
+Online statistics process a stream of samples one at a time, without storing the history.
+ */
+
+/// Tracks the minimum and maximum of a stream of samples.
+///
+/// NOTE: Incomparable samples such as `NaN` are ignored for the extremes, unless they are the first sample.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunningExtremes<T> {
+    extremes: Option<(T, T)>,
+    count: usize,
+}
+
+impl<T> Default for RunningExtremes<T> {
+    fn default() -> Self {
+        Self {
+            extremes: None,
+            count: 0,
+        }
+    }
+}
+
+impl<T> RunningExtremes<T>
+where
+    T: PartialOrd + Copy,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, x: T) {
+        self.count += 1;
+        self.extremes = match self.extremes {
+            None => Some((x, x)),
+            Some((min, max)) => {
+                Some((if x < min { x } else { min }, if x > max { x } else { max }))
+            }
+        };
+    }
+
+    /// The minimum of the samples, or `None` if no sample was pushed yet.
+    pub fn min(&self) -> Option<T> {
+        self.extremes.map(|(min, _)| min)
+    }
+
+    /// The maximum of the samples, or `None` if no sample was pushed yet.
+    pub fn max(&self) -> Option<T> {
+        self.extremes.map(|(_, max)| max)
+    }
+
+    /// The number of samples pushed.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<T> Extend<T> for RunningExtremes<T>
+where
+    T: PartialOrd + Copy,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+/// Accumulates the central moments of a stream of samples by the numerically stable
+/// [higher-order Welford recurrences](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Higher-order_statistics),
+/// providing the mean, the variance, the skewness and the kurtosis in a single pass.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MomentAccumulator<T> {
+    n: T,
+    count: usize,
+    mean: T,
+    // the sums of the powers of the differences from the mean
+    m2: T,
+    m3: T,
+    m4: T,
+}
+
+impl<T> Default for MomentAccumulator<T>
+where
+    T: NumericField + Copy,
+{
+    fn default() -> Self {
+        Self {
+            n: T::zero(),
+            count: 0,
+            mean: T::zero(),
+            m2: T::zero(),
+            m3: T::zero(),
+            m4: T::zero(),
+        }
+    }
+}
+
+impl<T> MomentAccumulator<T>
+where
+    T: RealField + Copy,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, x: T) {
+        let n_prev = self.n;
+        self.n = self.n + T::one();
+        self.count += 1;
+
+        let n = self.n;
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * n_prev;
+
+        let two = T::one() + T::one();
+        let three = two + T::one();
+        self.mean = self.mean + delta_n;
+        self.m4 = self.m4
+            + term * delta_n2 * (n * n - three * n + three)
+            + two * three * delta_n2 * self.m2
+            - two * two * delta_n * self.m3;
+        self.m3 = self.m3 + term * delta_n * (n - two) - three * delta_n * self.m2;
+        self.m2 = self.m2 + term;
+    }
+
+    /// The number of samples pushed.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The mean of the samples, or `None` if no sample was pushed yet.
+    pub fn mean(&self) -> Option<T> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(self.mean)
+    }
+
+    /// The sample `variance`, or `None` for less than two samples.
+    pub fn variance(&self) -> Option<T> {
+        if self.count < 2 {
+            return None;
+        }
+        Some(self.m2 / (self.n - T::one()))
+    }
+
+    /// The `skewness`, or `None` for less than two samples or constant samples.
+    pub fn skewness(&self) -> Option<T> {
+        if self.count < 2 || self.m2 == T::zero() {
+            return None;
+        }
+        Some(self.n.sqrt() * self.m3 / (self.m2 * self.m2.sqrt()))
+    }
+
+    /// The excess `kurtosis`, or `None` for less than two samples or constant samples.
+    pub fn kurtosis(&self) -> Option<T> {
+        if self.count < 2 || self.m2 == T::zero() {
+            return None;
+        }
+        let three = T::one() + T::one() + T::one();
+        Some(self.n * self.m4 / (self.m2 * self.m2) - three)
+    }
+}
+
+/// Accumulates the sums $\Sigma x_i$, $\Sigma y_i$, $\Sigma x_i^2$, $\Sigma y_i^2$ and $\Sigma x_i y_i$ of a stream of pairs,
+/// which provide all second-order statistics of the pairs, such as the
+/// [Pearson correlation coefficient](https://en.wikipedia.org/wiki/Pearson_correlation_coefficient), in a single pass.
+///
+/// NOTE: The raw sums are prone to cancellation for samples with a large mean and a small spread,
+/// see `correlation_iter` for a numerically stable alternative.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PearsonAccumulator<T> {
+    n: T,
+    count: usize,
+    sum_x: T,
+    sum_y: T,
+    sum_xx: T,
+    sum_yy: T,
+    sum_xy: T,
+}
+
+impl<T> Default for PearsonAccumulator<T>
+where
+    T: NumericField + Copy,
+{
+    fn default() -> Self {
+        Self {
+            n: T::zero(),
+            count: 0,
+            sum_x: T::zero(),
+            sum_y: T::zero(),
+            sum_xx: T::zero(),
+            sum_yy: T::zero(),
+            sum_xy: T::zero(),
+        }
+    }
+}
+
+impl<T> PearsonAccumulator<T>
+where
+    T: NumericField + Copy,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, x: T, y: T) {
+        self.n = self.n + T::one();
+        self.count += 1;
+        self.sum_x = self.sum_x + x;
+        self.sum_y = self.sum_y + y;
+        self.sum_xx = self.sum_xx + x * x;
+        self.sum_yy = self.sum_yy + y * y;
+        self.sum_xy = self.sum_xy + x * y;
+    }
+
+    /// The number of pairs pushed.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The co-moment $ n \Sigma a_i b_i - \Sigma a_i \Sigma b_i $, that is $n^2$ times the biased covariance.
+    fn co_moment(&self, sum_ab: T, sum_a: T, sum_b: T) -> T {
+        self.n * sum_ab - sum_a * sum_b
+    }
+
+    /// The unbiased estimator $ co\_moment / (n (n - 1)) $, or `None` for less than two pairs.
+    fn sample_estimate(&self, sum_ab: T, sum_a: T, sum_b: T) -> Option<T> {
+        if self.count < 2 {
+            return None;
+        }
+        Some(self.co_moment(sum_ab, sum_a, sum_b) / (self.n * (self.n - T::one())))
+    }
+
+    /// The sample covariance of the pairs, or `None` for less than two pairs.
+    pub fn covariance(&self) -> Option<T> {
+        self.sample_estimate(self.sum_xy, self.sum_x, self.sum_y)
+    }
+
+    /// The sample variance of the first components, or `None` for less than two pairs.
+    pub fn variance_x(&self) -> Option<T> {
+        self.sample_estimate(self.sum_xx, self.sum_x, self.sum_x)
+    }
+
+    /// The sample variance of the second components, or `None` for less than two pairs.
+    pub fn variance_y(&self) -> Option<T> {
+        self.sample_estimate(self.sum_yy, self.sum_y, self.sum_y)
+    }
+}
+
+impl<T> PearsonAccumulator<T>
+where
+    T: RealField + Copy,
+{
+    /// The Pearson correlation coefficient $ r = cov(x, y) / (\sigma_x \sigma_y) $.
+    /// Returns `None` for less than two pairs or if either component is constant.
+    pub fn correlation(&self) -> Option<T> {
+        if self.count < 2 {
+            return None;
+        }
+        let scale = (self.co_moment(self.sum_xx, self.sum_x, self.sum_x)
+            * self.co_moment(self.sum_yy, self.sum_y, self.sum_y))
+        .sqrt();
+        if scale == T::zero() {
+            return None;
+        }
+        Some(self.co_moment(self.sum_xy, self.sum_x, self.sum_y) / scale)
+    }
+}
+
+/// Accumulates the means and the co-moments $C = \Sigma (x_i - \bar x)(y_i - \bar y)$ of a stream of pairs by the
+/// [Welford recurrences](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Covariance).
+/// In contrast to the `PearsonAccumulator` it is numerically stable, and accumulators of separate chunks of the
+/// stream can be combined by `merge`, e.g. for a chunked or distributed computation.
+///
+/// The running means require an exact division, hence the accumulator is restricted to the `RealField`s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoMomentAccumulator<T> {
+    n: T,
+    count: usize,
+    mean_x: T,
+    mean_y: T,
+    m2_x: T,
+    m2_y: T,
+    c: T,
+}
+
+impl<T> Default for CoMomentAccumulator<T>
+where
+    T: RealField + Copy,
+{
+    fn default() -> Self {
+        Self {
+            n: T::zero(),
+            count: 0,
+            mean_x: T::zero(),
+            mean_y: T::zero(),
+            m2_x: T::zero(),
+            m2_y: T::zero(),
+            c: T::zero(),
+        }
+    }
+}
+
+impl<T> CoMomentAccumulator<T>
+where
+    T: RealField + Copy,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, x: T, y: T) {
+        self.n = self.n + T::one();
+        self.count += 1;
+
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x = self.mean_x + dx / self.n;
+        self.mean_y = self.mean_y + dy / self.n;
+
+        // one deviation from the previous and one from the updated mean
+        self.m2_x = self.m2_x + dx * (x - self.mean_x);
+        self.m2_y = self.m2_y + dy * (y - self.mean_y);
+        self.c = self.c + dx * (y - self.mean_y);
+    }
+
+    /// Combines the pairs of `other` into `self` by the
+    /// [parallel algorithm of Chan et al.](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Parallel_algorithm),
+    /// as if all pairs had been pushed into a single accumulator.
+    pub fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other.clone();
+            return;
+        }
+
+        let n = self.n + other.n;
+        let dx = other.mean_x - self.mean_x;
+        let dy = other.mean_y - self.mean_y;
+        let weight = self.n * other.n / n;
+
+        self.mean_x = self.mean_x + dx * other.n / n;
+        self.mean_y = self.mean_y + dy * other.n / n;
+        self.m2_x = self.m2_x + other.m2_x + dx * dx * weight;
+        self.m2_y = self.m2_y + other.m2_y + dy * dy * weight;
+        self.c = self.c + other.c + dx * dy * weight;
+        self.n = n;
+        self.count += other.count;
+    }
+
+    /// The number of pairs pushed or merged.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The means of the first and the second components, or `None` if no pair was pushed yet.
+    pub fn means(&self) -> Option<(T, T)> {
+        if self.count == 0 {
+            return None;
+        }
+        Some((self.mean_x, self.mean_y))
+    }
+
+    /// The co-moment `m` divided by the degrees of freedom $n - 1$ of the unbiased estimators,
+    /// or `None` for less than two pairs.
+    fn sample_estimate(&self, m: T) -> Option<T> {
+        if self.count < 2 {
+            return None;
+        }
+        Some(m / (self.n - T::one()))
+    }
+
+    /// The sample covariance of the pairs, or `None` for less than two pairs.
+    pub fn covariance(&self) -> Option<T> {
+        self.sample_estimate(self.c)
+    }
+
+    /// The sample variance of the first components, or `None` for less than two pairs.
+    pub fn variance_x(&self) -> Option<T> {
+        self.sample_estimate(self.m2_x)
+    }
+
+    /// The sample variance of the second components, or `None` for less than two pairs.
+    pub fn variance_y(&self) -> Option<T> {
+        self.sample_estimate(self.m2_y)
+    }
+
+    /// The Pearson correlation coefficient $ r = C / \sqrt{M_{2,x} M_{2,y}} $.
+    /// Returns `None` for less than two pairs or if either component is constant.
+    pub fn correlation(&self) -> Option<T> {
+        if self.count < 2 {
+            return None;
+        }
+        let scale = (self.m2_x * self.m2_y).sqrt();
+        if scale == T::zero() {
+            return None;
+        }
+        Some(self.c / scale)
+    }
+}
+
+/// Keeps a uniform random subsample of fixed size of a stream of samples by
+/// [reservoir sampling](https://en.wikipedia.org/wiki/Reservoir_sampling#Simple:_Algorithm_R) (Algorithm R),
+/// e.g. to estimate percentiles of a stream too large to be stored and sorted.
+///
+/// The replacements are drawn from a seeded pseudo random number generator, hence the sample is reproducible.
+#[derive(Debug, Clone)]
+pub struct ReservoirSampler<T> {
+    reservoir: Vec<T>,
+    capacity: usize,
+    count: usize,
+    state: u64,
+}
+
+impl<T> ReservoirSampler<T> {
+    /// Creates a sampler keeping at most `capacity` samples.
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize, seed: u64) -> Self {
+        assert!(capacity > 0, "the reservoir capacity must be positive");
+        Self {
+            reservoir: Vec::with_capacity(capacity),
+            capacity,
+            count: 0,
+            state: seed,
+        }
+    }
+
+    pub fn push(&mut self, x: T) {
+        self.count += 1;
+        if self.reservoir.len() < self.capacity {
+            self.reservoir.push(x);
+            return;
+        }
+        // replace a random element with probability capacity / count
+        let idx = self.next_index(self.count);
+        if idx < self.capacity {
+            self.reservoir[idx] = x;
+        }
+    }
+
+    /// The current subsample, in no particular order.
+    pub fn sample(&self) -> &[T] {
+        &self.reservoir
+    }
+
+    /// The number of samples pushed.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// A uniform index in `0..n` from the [SplitMix64](https://prng.di.unimi.it/splitmix64.c) generator.
+    fn next_index(&mut self, n: usize) -> usize {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        // multiply-shift instead of the biased modulo
+        ((z as u128 * n as u128) >> 64) as usize
+    }
+}
+
+impl<T> Extend<T> for ReservoirSampler<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CoMomentAccumulator, MomentAccumulator, PearsonAccumulator, ReservoirSampler,
+        RunningExtremes,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn running_extremes() {
+        let mut extremes = RunningExtremes::new();
+        assert_eq!(extremes.min(), None);
+        assert_eq!(extremes.max(), None);
+        assert_eq!(extremes.count(), 0);
+
+        // a permutation of 0..101
+        let xs: Vec<i32> = (0..101).map(|i| (i * 37) % 101 - 50).collect();
+        for x in xs.iter() {
+            extremes.push(*x);
+        }
+
+        assert_eq!(extremes.min(), xs.iter().min().copied());
+        assert_eq!(extremes.max(), xs.iter().max().copied());
+        assert_eq!(extremes.min(), Some(-50));
+        assert_eq!(extremes.max(), Some(50));
+        assert_eq!(extremes.count(), 101);
+    }
+
+    #[test]
+    fn running_extremes_floats() {
+        let mut extremes = RunningExtremes::new();
+        extremes.extend([2.5, -1.0, f64::NAN, 7.0, 0.0]);
+        assert_eq!(extremes.min(), Some(-1.0));
+        assert_eq!(extremes.max(), Some(7.0));
+        assert_eq!(extremes.count(), 5);
+    }
+
+    #[test]
+    fn pearson_accumulator() {
+        use crate::descriptive_stats::{correlation_iter, covariance, Variance};
+
+        let xs = [2.1, 2.5, 3.6, 4.0, 5.2, 6.3];
+        let ys = [8.0, 10.0, 12.0, 14.0, 13.0, 20.0];
+
+        let mut acc = PearsonAccumulator::new();
+        assert_eq!(acc.correlation(), None);
+        for (x, y) in xs.iter().zip(ys.iter()) {
+            acc.push(*x, *y);
+        }
+        assert_eq!(acc.count(), 6);
+
+        let expected = correlation_iter(xs.iter().copied(), ys.iter().copied()).unwrap();
+        assert_abs_diff_eq!(acc.correlation().unwrap(), expected, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            acc.covariance().unwrap(),
+            covariance(&xs, &ys).unwrap(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            acc.variance_x().unwrap(),
+            xs.as_slice().sample_variance().unwrap(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            acc.variance_y().unwrap(),
+            ys.as_slice().sample_variance().unwrap(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn pearson_accumulator_integers() {
+        let mut acc = PearsonAccumulator::new();
+        for x in 1..=5_i64 {
+            acc.push(x, 2 * x);
+        }
+        assert_eq!(acc.covariance(), Some(5));
+        assert_eq!(acc.variance_x(), Some(2));
+        assert_eq!(acc.variance_y(), Some(10));
+    }
+
+    #[test]
+    fn pearson_accumulator_degenerate() {
+        let mut acc = PearsonAccumulator::new();
+        acc.push(1.0, 2.0);
+        assert_eq!(acc.covariance(), None);
+        assert_eq!(acc.correlation(), None);
+
+        // constant second component
+        acc.push(2.0, 2.0);
+        assert_eq!(acc.covariance(), Some(0.0));
+        assert_eq!(acc.correlation(), None);
+    }
+
+    #[test]
+    fn moment_accumulator() {
+        use crate::descriptive_stats::{kurtosis, mean, skewness, variance};
+
+        let xs = [
+            5.376_671_395_461e-1,
+            1.833_885_014_595_086_5,
+            -2.258_846_861_003_648,
+            8.621_733_203_681_206e-1,
+            3.187_652_398_589_808e-1,
+            -1.307_688_296_305_273_4,
+            -4.335_920_223_056_835_6e-1,
+            3.426_244_665_386_499e-1,
+            3.578_396_939_725_760_5,
+            2.769_437_029_884_877,
+        ];
+
+        let mut acc = MomentAccumulator::new();
+        assert_eq!(acc.mean(), None);
+        for x in xs {
+            acc.push(x);
+        }
+        assert_eq!(acc.count(), xs.len());
+
+        let epsilon = 1e-14;
+        assert_abs_diff_eq!(acc.mean().unwrap(), mean(&xs).unwrap(), epsilon = epsilon);
+        assert_abs_diff_eq!(
+            acc.variance().unwrap(),
+            variance(&xs, None).unwrap(),
+            epsilon = epsilon
+        );
+        assert_abs_diff_eq!(
+            acc.skewness().unwrap(),
+            skewness(&xs).unwrap(),
+            epsilon = epsilon
+        );
+        assert_abs_diff_eq!(
+            acc.kurtosis().unwrap(),
+            kurtosis(&xs).unwrap(),
+            epsilon = epsilon
+        );
+    }
+
+    #[test]
+    fn moment_accumulator_undersized() {
+        let mut acc = MomentAccumulator::new();
+        acc.push(1.0);
+        assert_eq!(acc.mean(), Some(1.0));
+        assert_eq!(acc.variance(), None);
+        assert_eq!(acc.skewness(), None);
+        assert_eq!(acc.kurtosis(), None);
+
+        acc.push(1.0);
+        assert_eq!(acc.variance(), Some(0.0));
+        assert_eq!(acc.skewness(), None);
+    }
+
+    #[test]
+    fn co_moment_accumulator_merge() {
+        use crate::descriptive_stats::{correlation_iter, covariance, Variance};
+
+        let xs = [2.1, 2.5, 3.6, 4.0, 5.2, 6.3, 1.7, 3.3, 4.4];
+        let ys = [8.0, 10.0, 12.0, 14.0, 13.0, 20.0, 7.5, 11.0, 9.0];
+
+        let accumulate = |range: std::ops::Range<usize>| {
+            let mut acc = CoMomentAccumulator::new();
+            for idx in range {
+                acc.push(xs[idx], ys[idx]);
+            }
+            acc
+        };
+        let mut merged = accumulate(0..4);
+        merged.merge(&accumulate(4..9));
+        assert_eq!(merged.count(), 9);
+
+        assert_abs_diff_eq!(
+            merged.covariance().unwrap(),
+            covariance(&xs, &ys).unwrap(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            merged.variance_x().unwrap(),
+            xs.as_slice().sample_variance().unwrap(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            merged.variance_y().unwrap(),
+            ys.as_slice().sample_variance().unwrap(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            merged.correlation().unwrap(),
+            correlation_iter(xs.iter().copied(), ys.iter().copied()).unwrap(),
+            epsilon = 1e-12
+        );
+
+        // agrees with a single accumulator over all pairs
+        let single = accumulate(0..9);
+        let (mean_x, mean_y) = merged.means().unwrap();
+        let (single_x, single_y) = single.means().unwrap();
+        assert_abs_diff_eq!(mean_x, single_x, epsilon = 1e-12);
+        assert_abs_diff_eq!(mean_y, single_y, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            merged.covariance().unwrap(),
+            single.covariance().unwrap(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn co_moment_accumulator_degenerate() {
+        let mut acc = CoMomentAccumulator::new();
+        assert_eq!(acc.means(), None);
+        acc.merge(&CoMomentAccumulator::new());
+        assert_eq!(acc.count(), 0);
+
+        let mut other = CoMomentAccumulator::new();
+        other.push(1.0, 2.0);
+        acc.merge(&other);
+        assert_eq!(acc, other);
+        assert_eq!(acc.covariance(), None);
+
+        // a constant component has no correlation
+        acc.push(1.0, 3.0);
+        assert_eq!(acc.covariance(), Some(0.0));
+        assert_eq!(acc.correlation(), None);
+    }
+
+    #[test]
+    fn reservoir_sampler() {
+        let n = 100_000;
+        let mut sampler = ReservoirSampler::new(1_000, 42);
+        sampler.extend((0..n).map(|i| i as f64));
+        assert_eq!(sampler.count(), n);
+        assert_eq!(sampler.sample().len(), 1_000);
+
+        // the stream mean is (n - 1) / 2 with standard deviation of about n / sqrt(12)
+        let mean = sampler.sample().iter().sum::<f64>() / 1_000.0;
+        let stream_mean = (n - 1) as f64 / 2.0;
+        assert!((mean - stream_mean).abs() < 0.05 * n as f64);
+
+        // reproducible for the same seed
+        let mut other = ReservoirSampler::new(1_000, 42);
+        other.extend((0..n).map(|i| i as f64));
+        assert_eq!(sampler.sample(), other.sample());
+    }
+
+    #[test]
+    fn reservoir_sampler_undersized() {
+        let mut sampler = ReservoirSampler::new(10, 7);
+        sampler.extend([3, 1, 2]);
+        assert_eq!(sampler.sample(), &[3, 1, 2]);
+        assert_eq!(sampler.capacity(), 10);
+    }
+}
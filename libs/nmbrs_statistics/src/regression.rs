@@ -0,0 +1,209 @@
+use crate::descriptive_stats::{covariance, mean, variance, Percentile, VarianceBias};
+use nmbrs_algebra::solve_linear_system_dyn;
+
+/// [Simple linear regression](https://en.wikipedia.org/wiki/Simple_linear_regression) by ordinary least squares,
+/// returning `(slope, intercept)` of the line $y = \beta_1 x + \beta_0$.
+///
+/// Returns `None` for fewer than two points, inconsistent dimensions or constant `xs`.
+pub fn linear_regression(xs: &[f64], ys: &[f64]) -> Option<(f64, f64)> {
+    if xs.len() < 2 || xs.len() != ys.len() {
+        return None;
+    }
+
+    // `covariance` is normalized as the sample variance
+    let var_x = variance(xs, Some(VarianceBias::Sample))?;
+    if var_x == 0.0 {
+        return None;
+    }
+    let slope = covariance(xs, ys)? / var_x;
+    let intercept = mean(ys)? - slope * mean(xs)?;
+    Some((slope, intercept))
+}
+
+/// The [Theil-Sen estimator](https://en.wikipedia.org/wiki/Theil%E2%80%93Sen_estimator), a regression line
+/// which is robust against outliers, returned as `(slope, intercept)`.
+///
+/// The slope is the median of the slopes through all pairs of points with distinct `x` values,
+/// the intercept is the median of $y_i - slope \cdot x_i$.
+/// Takes $O(n^2 \log n)$ time.
+///
+/// Returns `None` for fewer than two points, inconsistent dimensions or constant `xs`.
+pub fn theil_sen(xs: &[f64], ys: &[f64]) -> Option<(f64, f64)> {
+    if xs.len() < 2 || xs.len() != ys.len() {
+        return None;
+    }
+
+    let mut slopes = Vec::with_capacity(xs.len() * (xs.len() - 1) / 2);
+    for i in 0..xs.len() {
+        for j in (i + 1)..xs.len() {
+            let dx = xs[j] - xs[i];
+            if dx != 0.0 {
+                slopes.push((ys[j] - ys[i]) / dx);
+            }
+        }
+    }
+    slopes.sort_by(f64::total_cmp);
+    let slope = slopes.median()?;
+
+    let mut residuals: Vec<f64> = xs
+        .iter()
+        .zip(ys.iter())
+        .map(|(x, y)| y - slope * x)
+        .collect();
+    residuals.sort_by(f64::total_cmp);
+    let intercept = residuals.median()?;
+
+    Some((slope, intercept))
+}
+
+/// [Multiple linear regression](https://en.wikipedia.org/wiki/Linear_regression) by means of the
+/// [normal equations](https://en.wikipedia.org/wiki/Ordinary_least_squares#Matrix/vector_formulation) $X^T X \beta = X^T y$.
+///
+/// The predictors `x` are given as one slice per predictor, each containing one value per observation of `y`.
+/// The design matrix $X$ consists of an intercept column followed by the predictors, hence the
+/// coefficients are returned as $(\beta_0, \beta_1, ..., \beta_k)$ with the intercept $\beta_0$.
+///
+/// The predictors are centered and scaled before forming $X^T X$, which keeps the normal equations well-conditioned
+/// for predictors with a large offset, e.g. timestamps, and the coefficients are mapped back afterwards.
+///
+/// Returns `None` if the dimensions are inconsistent or the normal equations are singular,
+/// e.g. for constant or collinear predictors or fewer observations than coefficients.
+pub fn multiple_linear_regression(x: &[&[f64]], y: &[f64]) -> Option<Vec<f64>> {
+    let n = y.len();
+    if n == 0 || x.iter().any(|x_j| x_j.len() != n) {
+        return None;
+    }
+
+    // the mean and the standard deviation of each predictor
+    let mut moments = Vec::with_capacity(x.len());
+    for x_j in x {
+        let std_dev = variance(x_j, Some(VarianceBias::Population))?.sqrt();
+        if std_dev == 0.0 {
+            return None;
+        }
+        moments.push((mean(x_j)?, std_dev));
+    }
+
+    // the columns of the design matrix with standardized predictors
+    let intercept = vec![1.0; n];
+    let standardized: Vec<Vec<f64>> = x
+        .iter()
+        .zip(moments.iter())
+        .map(|(x_j, (mean_j, std_dev_j))| {
+            x_j.iter().map(|x_ij| (x_ij - mean_j) / std_dev_j).collect()
+        })
+        .collect();
+    let columns: Vec<&[f64]> = std::iter::once(intercept.as_slice())
+        .chain(standardized.iter().map(Vec::as_slice))
+        .collect();
+
+    let dot = |u: &[f64], v: &[f64]| u.iter().zip(v.iter()).map(|(u_i, v_i)| u_i * v_i).sum();
+    let xtx: Vec<Vec<f64>> = columns
+        .iter()
+        .map(|c_i| columns.iter().map(|c_j| dot(c_i, c_j)).collect())
+        .collect();
+    let xty: Vec<f64> = columns.iter().map(|c_i| dot(c_i, y)).collect();
+
+    // map back: y = gamma_0 + sum_j gamma_j (x_j - mean_j) / std_dev_j
+    let mut beta = solve_linear_system_dyn(xtx, xty)?;
+    for (beta_j, (_, std_dev_j)) in beta.iter_mut().skip(1).zip(moments.iter()) {
+        *beta_j /= std_dev_j;
+    }
+    beta[0] -= beta
+        .iter()
+        .skip(1)
+        .zip(moments.iter())
+        .map(|(beta_j, (mean_j, _))| beta_j * mean_j)
+        .sum::<f64>();
+    Some(beta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{linear_regression, multiple_linear_regression, theil_sen};
+
+    #[test]
+    fn multiple_linear_regression_exact() {
+        let x1 = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let x2 = [2.0, -1.0, 0.5, 3.0, 1.0, -2.0];
+        let y: Vec<f64> = x1
+            .iter()
+            .zip(x2.iter())
+            .map(|(a, b)| 1.5 + 2.0 * a - 0.5 * b)
+            .collect();
+
+        let beta = multiple_linear_regression(&[&x1, &x2], &y).unwrap();
+        for (b_i, expected) in beta.iter().zip([1.5, 2.0, -0.5]) {
+            assert!((b_i - expected).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn multiple_linear_regression_invalid() {
+        let x1 = [1.0, 2.0, 3.0];
+        let y = [1.0, 2.0, 3.0];
+
+        // inconsistent dimensions
+        assert_eq!(multiple_linear_regression(&[&x1[..2]], &y), None);
+        assert_eq!(multiple_linear_regression(&[], &[]), None);
+
+        // collinear predictors
+        let x2 = [2.0, 4.0, 6.0];
+        assert_eq!(multiple_linear_regression(&[&x1, &x2], &y), None);
+
+        // a constant predictor is collinear with the intercept
+        assert_eq!(multiple_linear_regression(&[&[1.0; 3]], &y), None);
+    }
+
+    #[test]
+    fn multiple_linear_regression_large_offset() {
+        // hourly timestamps
+        let x1: Vec<f64> = (0..24).map(|i| 1.7e9 + 3600.0 * i as f64).collect();
+        let x2: Vec<f64> = (0..24).map(|i| ((i * 7) % 5) as f64).collect();
+        let y: Vec<f64> = x1
+            .iter()
+            .zip(x2.iter())
+            .map(|(a, b)| 3.0 + 1e-3 * a - 2.0 * b)
+            .collect();
+
+        let beta = multiple_linear_regression(&[&x1, &x2], &y).unwrap();
+        // the intercept is extrapolated far away from the observations
+        assert!((beta[0] - 3.0).abs() < 1e-4);
+        assert!((beta[1] - 1e-3).abs() < 1e-13);
+        assert!((beta[2] - -2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn linear_regression_exact() {
+        let xs = [1.0, 2.0, 3.0, 4.0];
+        let ys = [3.0, 5.0, 7.0, 9.0];
+        let (slope, intercept) = linear_regression(&xs, &ys).unwrap();
+        assert!((slope - 2.0).abs() < 1e-12);
+        assert!((intercept - 1.0).abs() < 1e-12);
+
+        assert_eq!(linear_regression(&[1.0], &[1.0]), None);
+        assert_eq!(linear_regression(&[1.0, 1.0], &[1.0, 2.0]), None);
+    }
+
+    #[test]
+    fn theil_sen_outlier() {
+        let xs: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        let mut ys: Vec<f64> = xs.iter().map(|x| 2.0 * x + 1.0).collect();
+        ys[9] = 100.0;
+
+        let (slope, intercept) = theil_sen(&xs, &ys).unwrap();
+        assert!((slope - 2.0).abs() < 1e-12);
+        assert!((intercept - 1.0).abs() < 1e-12);
+
+        let (ols_slope, ols_intercept) = linear_regression(&xs, &ys).unwrap();
+        assert!((ols_slope - 2.0).abs() > 1.0);
+        assert!((ols_intercept - 1.0).abs() > 1.0);
+    }
+
+    #[test]
+    fn theil_sen_invalid() {
+        assert_eq!(theil_sen(&[1.0], &[1.0]), None);
+        assert_eq!(theil_sen(&[1.0, 2.0], &[1.0]), None);
+        assert_eq!(theil_sen(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0]), None);
+    }
+}
@@ -3,7 +3,7 @@ pub trait AsSlice<T> {
     fn as_slice(&self) -> &[T];
 }
 
-impl<'a, T> AsSlice<T> for &'a [T] {
+impl<T> AsSlice<T> for &[T] {
     fn as_slice(&self) -> &[T] {
         self
     }
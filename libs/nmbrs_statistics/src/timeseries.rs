@@ -0,0 +1,173 @@
+use crate::descriptive_stats::mean;
+use crate::regression::linear_regression;
+use nmbrs_algebra::RealField;
+
+/// The normalized [cross-correlation](https://en.wikipedia.org/wiki/Cross-correlation) of two series
+/// for the lags `-max_lag..=max_lag`, i.e. the returned vector has length `2 * max_lag + 1` with lag $0$ in the middle.
+///
+/// For the lag $k$ it is given by
+/// $ r_k = \frac{\Sigma_t (x_t - \bar{x})(y_{t+k} - \bar{y})}{\sqrt{\Sigma_t (x_t - \bar{x})^2 \Sigma_t (y_t - \bar{y})^2}} $,
+/// where the sum in the numerator runs over all overlapping indices. Hence a positive lag $k$
+/// corresponds to `ys` lagging behind `xs` by $k$ samples.
+///
+/// Returns `None` if the lengths do not match, the series are empty or one of them is constant.
+pub fn cross_correlation<T>(xs: &[T], ys: &[T], max_lag: usize) -> Option<Vec<T>>
+where
+    T: RealField + Copy,
+{
+    if xs.is_empty() || xs.len() != ys.len() {
+        return None;
+    }
+
+    let x_mean = mean(xs)?;
+    let y_mean = mean(ys)?;
+    let x_err: Vec<T> = xs.iter().map(|x| *x - x_mean).collect();
+    let y_err: Vec<T> = ys.iter().map(|y| *y - y_mean).collect();
+
+    let sum_of_squares = |errs: &[T]| errs.iter().fold(T::zero(), |acc, e| acc + *e * *e);
+    let norm = (sum_of_squares(&x_err) * sum_of_squares(&y_err)).sqrt();
+    if norm == T::zero() {
+        return None;
+    }
+
+    // the (unnormalized) correlation of `xs[t]` with `ys[t + shift]`
+    let lagged_sum = |x_err: &[T], y_err: &[T], shift: usize| {
+        x_err
+            .iter()
+            .zip(y_err.iter().skip(shift))
+            .fold(T::zero(), |acc, (x, y)| acc + *x * *y)
+    };
+
+    let negative_lags = (1..=max_lag).rev().map(|k| lagged_sum(&y_err, &x_err, k));
+    let non_negative_lags = (0..=max_lag).map(|k| lagged_sum(&x_err, &y_err, k));
+
+    Some(
+        negative_lags
+            .chain(non_negative_lags)
+            .map(|r| r / norm)
+            .collect(),
+    )
+}
+
+/// The lag of the peak of `cross_correlations` as returned by `cross_correlation`,
+/// e.g. an estimate for the time shift between two series.
+///
+/// Returns `None` if `cross_correlations` is not of odd length, or contains incomparable values.
+pub fn argmax_lag<T>(cross_correlations: &[T]) -> Option<isize>
+where
+    T: PartialOrd,
+{
+    if cross_correlations.len().is_multiple_of(2) {
+        return None;
+    }
+    let max_lag = (cross_correlations.len() / 2) as isize;
+
+    let mut argmax = 0;
+    for (idx, r) in cross_correlations.iter().enumerate().skip(1) {
+        if *r > cross_correlations[argmax] {
+            argmax = idx;
+        } else if r.partial_cmp(&cross_correlations[argmax]).is_none() {
+            return None;
+        }
+    }
+
+    Some(argmax as isize - max_lag)
+}
+
+/// Removes the mean of the series, such that the result is centered around zero.
+pub fn detrend_mean(xs: &[f64]) -> Vec<f64> {
+    let Some(x_mean) = mean(xs) else {
+        return Vec::with_capacity(0);
+    };
+    xs.iter().map(|x| x - x_mean).collect()
+}
+
+/// Removes the least-squares line through the points $(t, x_t)$ from the series, e.g. before computing
+/// correlations, which a linear trend would otherwise dominate. The result has zero mean and zero slope.
+///
+/// Falls back to `detrend_mean` for fewer than two samples.
+pub fn detrend_linear(xs: &[f64]) -> Vec<f64> {
+    let ts: Vec<f64> = (0..xs.len()).map(|t| t as f64).collect();
+    let Some((slope, intercept)) = linear_regression(&ts, xs) else {
+        return detrend_mean(xs);
+    };
+    xs.iter()
+        .zip(ts.iter())
+        .map(|(x, t)| x - (slope * t + intercept))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{argmax_lag, cross_correlation, detrend_linear, detrend_mean};
+    use crate::descriptive_stats::mean;
+    use crate::regression::linear_regression;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn cross_correlation_shift() {
+        // a pseudo random signal
+        let xs: Vec<f64> =
+            std::iter::successors(Some(1_u64), |x| Some((x * 48_271) % 2_147_483_647))
+                .take(50)
+                .map(|x| x as f64 / 2_147_483_647.0)
+                .collect();
+
+        // `ys` lags behind `xs` by 3 samples
+        let shift = 3;
+        let ys: Vec<f64> = (0..50).map(|t| xs[t.max(shift) - shift]).collect();
+
+        let rs = cross_correlation(&xs, &ys, 5).unwrap();
+        assert_eq!(rs.len(), 11);
+        assert_eq!(argmax_lag(&rs), Some(3));
+
+        // and vice versa
+        let rs = cross_correlation(&ys, &xs, 5).unwrap();
+        assert_eq!(argmax_lag(&rs), Some(-3));
+    }
+
+    #[test]
+    fn cross_correlation_identical() {
+        let xs = [1.0, 3.0, 2.0, 5.0, 4.0];
+        let rs = cross_correlation(&xs, &xs, 2).unwrap();
+        assert_abs_diff_eq!(rs[2], 1.0, epsilon = 1e-15);
+        assert_eq!(rs[0], rs[4]);
+        assert_eq!(argmax_lag(&rs), Some(0));
+    }
+
+    #[test]
+    fn cross_correlation_invalid() {
+        assert_eq!(cross_correlation(&[1.0, 2.0], &[1.0], 1), None);
+        assert_eq!(cross_correlation::<f64>(&[], &[], 1), None);
+        assert_eq!(cross_correlation(&[1.0, 1.0], &[1.0, 2.0], 1), None);
+
+        assert_eq!(argmax_lag::<f64>(&[]), None);
+        assert_eq!(argmax_lag(&[1.0, 2.0]), None);
+        assert_eq!(argmax_lag(&[1.0, f64::NAN, 0.0]), None);
+    }
+
+    #[test]
+    fn detrend() {
+        // a strong linear trend with a small oscillation
+        let xs: Vec<f64> = (0..100)
+            .map(|t| 5.0 + 3.0 * t as f64 + (t as f64).sin())
+            .collect();
+        let ts: Vec<f64> = (0..100).map(|t| t as f64).collect();
+
+        let detrended = detrend_linear(&xs);
+        assert_eq!(detrended.len(), xs.len());
+        let (slope, intercept) = linear_regression(&ts, &detrended).unwrap();
+        assert_abs_diff_eq!(slope, 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(intercept, 0.0, epsilon = 1e-10);
+        // the oscillation remains
+        assert!(detrended.iter().all(|x| x.abs() < 1.1));
+
+        let centered = detrend_mean(&xs);
+        assert_abs_diff_eq!(mean(&centered).unwrap(), 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(centered[1] - centered[0], xs[1] - xs[0], epsilon = 1e-12);
+
+        assert_eq!(detrend_linear(&[4.0]), vec![0.0]);
+        assert!(detrend_linear(&[]).is_empty());
+        assert!(detrend_mean(&[]).is_empty());
+    }
+}